@@ -14,13 +14,24 @@
 #![allow(clippy::result_large_err)]
 
 use backtrace::Backtrace;
+use fancy_regex::Regex;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
 use reqwest::{
-    header::{HeaderMap, HeaderValue},
-    Client as HttpClient, StatusCode,
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE, USER_AGENT},
+    Client as HttpClient, Method, Proxy, RequestBuilder, StatusCode,
 };
+#[cfg(feature = "tls")]
+use reqwest::{Certificate, Identity};
 use serde::Serialize;
 use serde_json::{json, Map, Value};
+use std::cmp::Reverse;
+use std::collections::HashSet;
 use std::fmt;
+use std::future::Future;
+#[cfg(feature = "tls")]
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::error::Error;
 use crate::error::Error::{ClientErrorResponse, NotFound, ServerErrorResponse};
@@ -28,14 +39,23 @@ use crate::responses::{
     DeprecatedFeatureList, FeatureFlag, FeatureFlagList, FeatureFlagStability, FeatureFlagState,
     MessageList,
 };
+use crate::retries::RetryPolicy;
+#[cfg(feature = "tls")]
+use crate::tls;
 use crate::{
-    commons::{BindingDestinationType, SupportedProtocol, UserLimitTarget, VirtualHostLimitTarget},
+    commons::{
+        BindingDestinationType, Scheme, SupportedProtocol, TimeUnit, UserLimitTarget,
+        VirtualHostLimitTarget,
+    },
     path,
     requests::{
-        self, BulkUserDelete, EnforcedLimitParams, ExchangeParams, Permissions, PolicyParams,
+        self, Amqp091ShovelParams, Amqp10ShovelParams, BulkUserDelete, EnforcedLimitParams,
+        ExchangeParams, NodeInfoFlags, Permissions, PolicyParams, QueueFilter, QueuePageFilter,
         QueueParams, RuntimeParameterDefinition, UserParams, VirtualHostParams, XArguments,
+        SHOVEL_COMPONENT,
     },
     responses::{self, BindingInfo, DefinitionSet},
+    utils::{endpoint_from_parts, parse_definitions, query_string, regex_matches},
 };
 
 pub type HttpClientResponse = reqwest::Response;
@@ -43,6 +63,31 @@ pub type HttpClientError = crate::error::HttpClientError;
 
 pub type Result<T> = std::result::Result<T, HttpClientError>;
 
+/// The maximum page size supported by the paginated listing endpoints.
+pub const MAX_PAGE_SIZE: usize = 500;
+
+/// How often `Client#rebalance_queue_leaders_and_wait` polls queue leader placement.
+pub const REBALANCE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The `User-Agent` header value used unless [`ClientBuilder::with_user_agent`] is used.
+pub const DEFAULT_USER_AGENT: &str = concat!("rabbitmq-http-api-rs/", env!("CARGO_PKG_VERSION"));
+
+/// How many queue deletions `Client#delete_queues` performs concurrently.
+pub const DELETE_QUEUES_CONCURRENCY: usize = 8;
+
+/// How many queue purges `Client#purge_queues` performs concurrently.
+pub const PURGE_QUEUES_CONCURRENCY: usize = 8;
+
+/// How many exchange deletions `Client#delete_exchanges` performs concurrently.
+pub const DELETE_EXCHANGES_CONCURRENCY: usize = 8;
+
+/// How many binding deletions `Client#delete_bindings` performs concurrently.
+pub const DELETE_BINDINGS_CONCURRENCY: usize = 8;
+
+/// How many connection closures `Client#close_all_connections` and `Client#close_all_connections_in`
+/// perform concurrently.
+pub const CLOSE_CONNECTIONS_CONCURRENCY: usize = 8;
+
 /// A `ClientBuilder` can be used to create a `Client` with custom configuration.
 ///
 /// Example
@@ -64,7 +109,12 @@ pub struct ClientBuilder<E, U, P> {
     endpoint: E,
     username: U,
     password: P,
+    oauth2_token: Option<String>,
+    retry_policy: RetryPolicy,
+    request_timeout: Option<Duration>,
+    user_agent: String,
     client: HttpClient,
+    http_client_settings: HttpClientSettings,
 }
 
 impl Default for ClientBuilder<&'static str, &'static str, &'static str> {
@@ -83,7 +133,12 @@ impl ClientBuilder<&'static str, &'static str, &'static str> {
             endpoint: "http://localhost:15672/api",
             username: "guest",
             password: "guest",
+            oauth2_token: None,
+            retry_policy: RetryPolicy::none(),
+            request_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
             client,
+            http_client_settings: HttpClientSettings::default(),
         }
     }
 }
@@ -94,6 +149,10 @@ where
     U: fmt::Display,
     P: fmt::Display,
 {
+    /// Configures the client to use basic authentication with the given credentials.
+    ///
+    /// This replaces an [OAuth 2](https://rabbitmq.com/docs/oauth2/) token set earlier
+    /// with [`ClientBuilder::with_oauth2_token`], if any.
     pub fn with_basic_auth_credentials<NewU, NewP>(
         self,
         username: NewU,
@@ -107,7 +166,23 @@ where
             endpoint: self.endpoint,
             username,
             password,
+            oauth2_token: None,
+            retry_policy: self.retry_policy,
+            request_timeout: self.request_timeout,
+            user_agent: self.user_agent,
             client: self.client,
+            http_client_settings: self.http_client_settings,
+        }
+    }
+
+    /// Configures the client to authenticate with an [OAuth 2](https://rabbitmq.com/docs/oauth2/) token
+    /// (as `Authorization: Bearer <token>`) instead of basic authentication credentials.
+    ///
+    /// This replaces the credentials set earlier with [`ClientBuilder::with_basic_auth_credentials`].
+    pub fn with_oauth2_token(self, token: impl Into<String>) -> Self {
+        ClientBuilder {
+            oauth2_token: Some(token.into()),
+            ..self
         }
     }
 
@@ -119,17 +194,170 @@ where
             endpoint,
             username: self.username,
             password: self.password,
+            oauth2_token: self.oauth2_token,
+            retry_policy: self.retry_policy,
+            request_timeout: self.request_timeout,
+            user_agent: self.user_agent,
             client: self.client,
+            http_client_settings: self.http_client_settings,
+        }
+    }
+
+    /// Sets the endpoint composed out of a scheme, host, port and base path,
+    /// e.g. `Scheme::Https`, `"rabbitmq.eng.example.com"`, `15671` and `"/api"`.
+    /// An empty base path means the default one, `/api`.
+    ///
+    /// Returns an [`Error::InvalidEndpoint`] if the host is malformed or the resulting
+    /// endpoint does not parse as a URL.
+    pub fn with_endpoint_parts(
+        self,
+        scheme: Scheme,
+        host: &str,
+        port: u16,
+        base_path: &str,
+    ) -> Result<ClientBuilder<String, U, P>> {
+        let endpoint = endpoint_from_parts(scheme, host, port, base_path)?;
+        Ok(self.with_endpoint(endpoint))
+    }
+
+    /// Configures the client to retry requests that fail with transient errors, e.g. `503 Service Unavailable`
+    /// responses returned by a node that is restarting. By default, requests are not retried.
+    ///
+    /// Only idempotent requests (`GET`, `PUT`, `DELETE`) are retried unless the policy says otherwise.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        ClientBuilder {
+            retry_policy,
+            ..self
+        }
+    }
+
+    /// Sets a timeout applied to every request, from when the request starts connecting
+    /// until the response body has finished.
+    ///
+    /// This timeout is set on every request, so it overrides the timeout configured on
+    /// the `HttpClient` passed to [`ClientBuilder::with_client`], if any, whether shorter or longer.
+    pub fn with_default_timeout(self, timeout: Duration) -> Self {
+        ClientBuilder {
+            request_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    /// The default is [`DEFAULT_USER_AGENT`].
+    pub fn with_user_agent(self, user_agent: impl Into<String>) -> Self {
+        ClientBuilder {
+            user_agent: user_agent.into(),
+            ..self
         }
     }
 
+    /// Sets the `HttpClient` to use.
+    ///
+    /// This discards the TLS and proxy settings configured earlier with [`ClientBuilder::with_tls`]
+    /// and [`ClientBuilder::with_proxy`], if any.
     pub fn with_client(self, client: HttpClient) -> Self {
-        ClientBuilder { client, ..self }
+        ClientBuilder {
+            client,
+            http_client_settings: HttpClientSettings::default(),
+            ..self
+        }
+    }
+
+    /// Configures the client to send all requests via an HTTP(S) proxy, e.g. `http://proxy.eng.example.com:3128`.
+    /// Can be combined with [`ClientBuilder::with_tls`].
+    ///
+    /// This replaces the `HttpClient` set earlier with [`ClientBuilder::with_client`], if any.
+    /// Returns an [`Error::InvalidProxy`] if the proxy URL cannot be parsed, or an error
+    /// if the `HttpClient` cannot be initialized.
+    pub fn with_proxy(self, proxy_url: &str) -> Result<Self> {
+        let proxy = Proxy::all(proxy_url).map_err(|e| Error::InvalidProxy {
+            url: proxy_url.to_owned(),
+            reason: e.to_string(),
+        })?;
+        let mut http_client_settings = self.http_client_settings;
+        http_client_settings.proxy = Some(proxy);
+        let client = http_client_settings.build_client()?;
+        Ok(ClientBuilder {
+            client,
+            http_client_settings,
+            ..self
+        })
+    }
+
+    /// Configures TLS for connections to the HTTP API using PEM files: a CA certificate (bundle)
+    /// to add to the list of trusted roots and, for mutual TLS (x.509 peer verification),
+    /// a client certificate and its PKCS #8 private key.
+    ///
+    /// Can be combined with [`ClientBuilder::with_proxy`]. This replaces the `HttpClient` set earlier
+    /// with [`ClientBuilder::with_client`], if any.
+    /// Returns an [`Error::InvalidTlsConfiguration`] if a file cannot be read or parsed, or an error
+    /// if the `HttpClient` cannot be initialized.
+    #[cfg(feature = "tls")]
+    pub fn with_tls(
+        self,
+        ca_cert: Option<PathBuf>,
+        client_identity: Option<(PathBuf, PathBuf)>,
+    ) -> Result<Self> {
+        let root_certificate = match ca_cert {
+            Some(path) => Some(tls::load_ca_certificate(&path)?),
+            None => None,
+        };
+        let identity = match client_identity {
+            Some((certificate_path, key_path)) => {
+                Some(tls::load_client_identity(&certificate_path, &key_path)?)
+            }
+            None => None,
+        };
+        let mut http_client_settings = self.http_client_settings;
+        http_client_settings.root_certificate = root_certificate;
+        http_client_settings.identity = identity;
+        let client = http_client_settings.build_client()?;
+        Ok(ClientBuilder {
+            client,
+            http_client_settings,
+            ..self
+        })
     }
 
     /// Returns a `Client` that uses this `ClientBuilder` configuration.
     pub fn build(self) -> Client<E, U, P> {
-        Client::from_http_client(self.client, self.endpoint, self.username, self.password)
+        let mut client =
+            Client::from_http_client(self.client, self.endpoint, self.username, self.password);
+        client.oauth2_token = self.oauth2_token;
+        client.retry_policy = self.retry_policy;
+        client.request_timeout = self.request_timeout;
+        client.user_agent = self.user_agent;
+        client
+    }
+}
+
+/// The `HttpClient` settings accumulated by [`ClientBuilder::with_proxy`] and [`ClientBuilder::with_tls`],
+/// so that each of them can rebuild the `HttpClient` without discarding what the other one configured.
+#[derive(Default)]
+struct HttpClientSettings {
+    proxy: Option<Proxy>,
+    #[cfg(feature = "tls")]
+    root_certificate: Option<Certificate>,
+    #[cfg(feature = "tls")]
+    identity: Option<Identity>,
+}
+
+impl HttpClientSettings {
+    fn build_client(&self) -> Result<HttpClient> {
+        let mut builder = HttpClient::builder();
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        #[cfg(feature = "tls")]
+        if let Some(certificate) = &self.root_certificate {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        #[cfg(feature = "tls")]
+        if let Some(identity) = &self.identity {
+            builder = builder.identity(identity.clone());
+        }
+        Ok(builder.build()?)
     }
 }
 
@@ -158,10 +386,15 @@ where
 /// // fetch information and metrics of a specific queue
 /// let _ = rc.get_queue_info("/", "qq.1").await;
 /// ```
+#[derive(Clone)]
 pub struct Client<E, U, P> {
     endpoint: E,
     username: U,
     password: P,
+    oauth2_token: Option<String>,
+    retry_policy: RetryPolicy,
+    request_timeout: Option<Duration>,
+    user_agent: String,
     client: HttpClient,
 }
 
@@ -189,6 +422,10 @@ where
             endpoint,
             username,
             password,
+            oauth2_token: None,
+            retry_policy: RetryPolicy::none(),
+            request_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
             client,
         }
     }
@@ -212,6 +449,10 @@ where
             endpoint,
             username,
             password,
+            oauth2_token: None,
+            retry_policy: RetryPolicy::none(),
+            request_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
             client,
         }
     }
@@ -230,6 +471,13 @@ where
         Ok(response)
     }
 
+    /// Returns a summary of cluster membership: how many nodes there are, how many of them
+    /// are running and how many are in [maintenance mode](https://www.rabbitmq.com/docs/upgrade#maintenance-mode).
+    pub async fn cluster_summary(&self) -> Result<responses::ClusterSummary> {
+        let nodes = self.list_nodes().await?;
+        Ok(responses::ClusterSummary::from(nodes.as_slice()))
+    }
+
     /// Lists virtual hosts in the cluster.
     pub async fn list_vhosts(&self) -> Result<Vec<responses::VirtualHost>> {
         let response = self.http_get("vhosts", None, None).await?;
@@ -261,6 +509,50 @@ where
         Ok(response)
     }
 
+    /// Lists a page of client connections across the cluster.
+    ///
+    /// Page numbers start at 1, the page size must not exceed [`MAX_PAGE_SIZE`].
+    pub async fn list_connections_paged(
+        &self,
+        page: u32,
+        page_size: u32,
+    ) -> Result<responses::Page<responses::Connection>> {
+        let params = [
+            ("page", page.to_string()),
+            ("page_size", page_size.to_string()),
+        ];
+        let path = format!("connections?{}", query_string(&params));
+        let response = self.http_get(path, None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Returns a stream of client connections across the cluster.
+    ///
+    /// Connections are fetched lazily, one page of `page_size` connections at a time,
+    /// so they can be processed without holding all of them in memory. Since pages are fetched
+    /// at different points in time, connections opened or closed in between may be skipped or
+    /// returned twice.
+    pub fn connections_stream(
+        &self,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<responses::Connection>> + '_ {
+        stream::try_unfold(Some(1), move |next_page| async move {
+            let Some(page) = next_page else {
+                return Ok::<_, HttpClientError>(None);
+            };
+            let response = self.list_connections_paged(page, page_size).await?;
+            let next_page = if response.is_last() {
+                None
+            } else {
+                Some(page + 1)
+            };
+            let items = stream::iter(response.items.into_iter().map(Ok::<_, HttpClientError>));
+            Ok(Some((items, next_page)))
+        })
+        .try_flatten()
+    }
+
     pub async fn get_connection_info(&self, name: &str) -> Result<responses::Connection> {
         let response = self
             .http_get(path!("connections", name), None, None)
@@ -285,6 +577,12 @@ where
         Ok(response)
     }
 
+    /// Closes a connection, optionally providing a reason that will be logged by the node
+    /// and sent to the client.
+    ///
+    /// `name` is the connection name as returned by [`Client::list_connections`],
+    /// e.g. `127.0.0.1:51000 -> 127.0.0.1:5672`. It must not be URL-encoded by the caller:
+    /// all non-alphanumeric characters (including spaces, `:`, and `->`) will be percent-encoded by this function.
     pub async fn close_connection(&self, name: &str, reason: Option<&str>) -> Result<()> {
         match reason {
             None => {
@@ -306,6 +604,50 @@ where
         Ok(())
     }
 
+    /// Closes all client connections in the cluster, performing up to
+    /// [`CLOSE_CONNECTIONS_CONCURRENCY`] closures concurrently.
+    /// The optional reason will be logged by the nodes and sent to the clients.
+    ///
+    /// All closures are attempted even if some of them fail. The failures are then returned
+    /// as an [`Error::PartialFailure`].
+    pub async fn close_all_connections(&self, reason: Option<&str>) -> Result<()> {
+        let connections = self.list_connections().await?;
+        self.close_connections(connections, reason).await
+    }
+
+    /// Closes all client connections in the given virtual host, performing up to
+    /// [`CLOSE_CONNECTIONS_CONCURRENCY`] closures concurrently.
+    /// The optional reason will be logged by the nodes and sent to the clients.
+    ///
+    /// All closures are attempted even if some of them fail. The failures are then returned
+    /// as an [`Error::PartialFailure`].
+    pub async fn close_all_connections_in(&self, vhost: &str, reason: Option<&str>) -> Result<()> {
+        let connections = self.list_connections_in(vhost).await?;
+        self.close_connections(connections, reason).await
+    }
+
+    async fn close_connections(
+        &self,
+        connections: Vec<responses::Connection>,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let failures: Vec<(String, HttpClientError)> = stream::iter(connections)
+            .map(|conn| async move {
+                let result = self.close_connection(&conn.name, reason).await;
+                result.err().map(|e| (conn.name, e))
+            })
+            .buffer_unordered(CLOSE_CONNECTIONS_CONCURRENCY)
+            .filter_map(|failure| async move { failure })
+            .collect()
+            .await;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PartialFailure { failures })
+        }
+    }
+
     /// Lists all connections in the given virtual host.
     pub async fn list_connections_in(
         &self,
@@ -318,6 +660,39 @@ where
         Ok(response)
     }
 
+    /// Lists client connections across the cluster that use the given protocol.
+    ///
+    /// The filtering is performed on the client side. See [`responses::Connection::uses_protocol`]
+    /// for how connections are matched.
+    pub async fn list_connections_by_protocol(
+        &self,
+        protocol: SupportedProtocol,
+    ) -> Result<Vec<responses::Connection>> {
+        let connections = self.list_connections().await?;
+        let result = connections
+            .into_iter()
+            .filter(|conn| conn.uses_protocol(&protocol))
+            .collect();
+        Ok(result)
+    }
+
+    /// Lists connections in the given virtual host that belong to a specific user.
+    ///
+    /// The filtering is performed on the client side. To reduce the size of the payload
+    /// that has to be transferred and deserialized, the virtual host-scoped endpoint is used.
+    pub async fn list_connections_of_user_in_vhost(
+        &self,
+        virtual_host: &str,
+        username: &str,
+    ) -> Result<Vec<responses::Connection>> {
+        let connections = self.list_connections_in(virtual_host).await?;
+        let result = connections
+            .into_iter()
+            .filter(|conn| conn.username == username)
+            .collect();
+        Ok(result)
+    }
+
     /// Lists all connections of a specific user.
     pub async fn list_user_connections(
         &self,
@@ -349,6 +724,36 @@ where
         Ok(response)
     }
 
+    /// Returns the number of client connections in the cluster.
+    ///
+    /// Uses the object totals reported by [`Client::overview`], which is a single inexpensive request.
+    /// When a node does not report object totals (e.g. because metrics collection is disabled),
+    /// they are all zeroes. In that case, this function falls back to listing connections,
+    /// which is also inexpensive when there are no connections.
+    pub async fn count_connections(&self) -> Result<usize> {
+        let overview = self.overview().await?;
+        if overview.object_totals != responses::ObjectTotals::default() {
+            return Ok(overview.object_totals.connections as usize);
+        }
+        let connections = self.list_connections().await?;
+        Ok(connections.len())
+    }
+
+    /// Returns the number of channels in the cluster.
+    ///
+    /// Uses the object totals reported by [`Client::overview`], which is a single inexpensive request.
+    /// When a node does not report object totals (e.g. because metrics collection is disabled),
+    /// they are all zeroes. In that case, this function falls back to listing channels,
+    /// which is also inexpensive when there are no channels.
+    pub async fn count_channels(&self) -> Result<usize> {
+        let overview = self.overview().await?;
+        if overview.object_totals != responses::ObjectTotals::default() {
+            return Ok(overview.object_totals.channels as usize);
+        }
+        let channels = self.list_channels().await?;
+        Ok(channels.len())
+    }
+
     /// Lists all channels across the cluster.
     pub async fn list_channels(&self) -> Result<Vec<responses::Channel>> {
         let response = self.http_get("channels", None, None).await?;
@@ -365,6 +770,30 @@ where
         Ok(response)
     }
 
+    /// Lists channels across the cluster that have more than `threshold` messages
+    /// delivered but not yet acknowledged, which usually indicates stuck consumers.
+    pub async fn channels_with_unacked_above(
+        &self,
+        threshold: u64,
+    ) -> Result<Vec<responses::Channel>> {
+        let channels = self.list_channels().await?;
+        let result = channels
+            .into_iter()
+            .filter(|ch| u64::from(ch.messages_unacknowledged) > threshold)
+            .collect();
+        Ok(result)
+    }
+
+    /// Lists channels across the cluster that use a channel-wide ("global") prefetch limit.
+    pub async fn channels_with_global_prefetch(&self) -> Result<Vec<responses::Channel>> {
+        let channels = self.list_channels().await?;
+        let result = channels
+            .into_iter()
+            .filter(|ch| ch.has_global_prefetch())
+            .collect();
+        Ok(result)
+    }
+
     /// Lists all stream publishers across the cluster.
     pub async fn list_stream_publishers(&self) -> Result<Vec<responses::StreamPublisher>> {
         let response = self
@@ -442,6 +871,23 @@ where
         Ok(response)
     }
 
+    /// Lists consumers of the given stream.
+    ///
+    /// The HTTP API does not provide a stream-scoped consumer endpoint, so this function
+    /// filters the virtual host-scoped listing by stream name. If the stream has no consumers,
+    /// an empty vector is returned.
+    pub async fn list_stream_consumers_of(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<Vec<responses::StreamConsumer>> {
+        let consumers = self.list_stream_consumers_in(virtual_host).await?;
+        Ok(consumers
+            .into_iter()
+            .filter(|c| c.queue.name == name)
+            .collect())
+    }
+
     /// Lists stream consumers on the given stream connection.
     pub async fn list_stream_consumers_on_connection(
         &self,
@@ -476,6 +922,110 @@ where
         Ok(response)
     }
 
+    /// Lists queues and streams for which `predicate` returns `true`, in the given virtual host
+    /// or, if `virtual_host` is `None`, across the cluster.
+    ///
+    /// The predicate is applied client-side, after all queues have been fetched.
+    pub async fn list_queues_matching<F>(
+        &self,
+        virtual_host: Option<&str>,
+        predicate: F,
+    ) -> Result<Vec<responses::QueueInfo>>
+    where
+        F: Fn(&responses::QueueInfo) -> bool,
+    {
+        let queues = match virtual_host {
+            Some(vhost) => self.list_queues_in(vhost).await?,
+            None => self.list_queues().await?,
+        };
+        Ok(queues.into_iter().filter(|q| predicate(q)).collect())
+    }
+
+    /// Lists [exclusive](https://rabbitmq.com/docs/queues#exclusive-queues) queues in the given virtual host.
+    ///
+    /// The filtering is performed client-side, see [`Client::list_queues_matching`].
+    pub async fn list_exclusive_queues(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        self.list_queues_matching(Some(virtual_host), |q| q.exclusive)
+            .await
+    }
+
+    /// Lists auto-delete queues in the given virtual host.
+    ///
+    /// The filtering is performed client-side, see [`Client::list_queues_matching`].
+    pub async fn list_auto_delete_queues(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        self.list_queues_matching(Some(virtual_host), |q| q.auto_delete)
+            .await
+    }
+
+    /// Lists a page of queues and streams across the cluster, optionally filtered by name.
+    ///
+    /// Page numbers start at 1, the page size must not exceed [`MAX_PAGE_SIZE`].
+    pub async fn list_queues_paged(
+        &self,
+        page: u32,
+        page_size: u32,
+        filter: Option<&QueuePageFilter<'_>>,
+    ) -> Result<responses::Page<responses::QueueInfo>> {
+        self.get_queue_page("queues", page, page_size, filter).await
+    }
+
+    /// Lists a page of queues and streams in the given virtual host, optionally filtered by name.
+    ///
+    /// Page numbers start at 1, the page size must not exceed [`MAX_PAGE_SIZE`].
+    pub async fn list_queues_in_paged(
+        &self,
+        virtual_host: &str,
+        page: u32,
+        page_size: u32,
+        filter: Option<&QueuePageFilter<'_>>,
+    ) -> Result<responses::Page<responses::QueueInfo>> {
+        self.get_queue_page(&path!("queues", virtual_host), page, page_size, filter)
+            .await
+    }
+
+    /// Lists queues and streams that match the given [`QueueFilter`].
+    ///
+    /// The virtual host and name (regular expression) filters are applied server-side,
+    /// the queue type filter is applied client-side. The regular expression is validated
+    /// before any requests are sent. Like on the server, PCRE features such as look-around
+    /// assertions and backreferences are supported. All pages of results are fetched.
+    pub async fn list_queues_filtered(
+        &self,
+        filter: &QueueFilter,
+    ) -> Result<Vec<responses::QueueInfo>> {
+        let base = match &filter.vhost {
+            Some(vhost) => path!("queues", vhost),
+            None => "queues".to_owned(),
+        };
+        let page_filter = filter
+            .name_regex
+            .as_deref()
+            .map(QueuePageFilter::name_matching);
+
+        let mut result = Vec::new();
+        let mut page = 1;
+        loop {
+            let response = self
+                .get_queue_page(&base, page, MAX_PAGE_SIZE as u32, page_filter.as_ref())
+                .await?;
+            let is_last = response.is_last();
+            result.extend(response.items.into_iter().filter(|q| filter.matches(q)));
+
+            if is_last {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(result)
+    }
+
     /// Lists all exchanges across the cluster.
     pub async fn list_exchanges(&self) -> Result<Vec<responses::ExchangeInfo>> {
         let response = self.http_get("exchanges", None, None).await?;
@@ -483,6 +1033,13 @@ where
         Ok(response)
     }
 
+    /// Lists all exchanges across the cluster, including their message rates (when available).
+    pub async fn list_exchanges_with_rates(&self) -> Result<Vec<responses::ExchangeInfoWithRates>> {
+        let response = self.http_get("exchanges", None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
     /// Lists all exchanges in the given virtual host.
     pub async fn list_exchanges_in(
         &self,
@@ -514,6 +1071,34 @@ where
         Ok(response)
     }
 
+    /// Lists exchanges in the given virtual host that are not the source of any binding,
+    /// that is, nothing is routed out of them. Useful to find exchanges to clean up.
+    ///
+    /// Built-in exchanges (the default exchange and the `amq.*` ones) are never included.
+    pub async fn list_unused_exchanges(
+        &self,
+        virtual_host: &str,
+    ) -> Result<Vec<responses::ExchangeInfo>> {
+        let exchanges = self.list_exchanges_in(virtual_host).await?;
+        let bindings = self.list_bindings_in(virtual_host).await?;
+
+        let sources: HashSet<&str> = bindings.iter().map(|b| b.source.as_str()).collect();
+        Ok(exchanges
+            .into_iter()
+            .filter(|x| !x.is_built_in() && !sources.contains(x.name.as_str()))
+            .collect())
+    }
+
+    /// Returns the routing topology of a virtual host as a graph of exchanges, queues and streams
+    /// connected by bindings. See [`responses::RoutingGraph`] for what is included.
+    pub async fn exchange_routing_graph(
+        &self,
+        virtual_host: &str,
+    ) -> Result<responses::RoutingGraph> {
+        let bindings = self.list_bindings_in(virtual_host).await?;
+        Ok(responses::RoutingGraph::from_bindings(&bindings))
+    }
+
     /// Lists all bindings of a specific queue.
     pub async fn list_queue_bindings(
         &self,
@@ -527,6 +1112,42 @@ where
         Ok(response)
     }
 
+    /// Lists all bindings between an exchange and a queue (or stream).
+    pub async fn list_bindings_between_exchange_and_queue(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+        queue: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        let response = self
+            .http_get(
+                path!("bindings", virtual_host, "e", exchange, "q", queue),
+                None,
+                None,
+            )
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Lists all bindings between two exchanges ([exchange-to-exchange bindings](https://rabbitmq.com/docs/e2e/)).
+    pub async fn list_bindings_between_exchanges(
+        &self,
+        virtual_host: &str,
+        source: &str,
+        destination: &str,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        let response = self
+            .http_get(
+                path!("bindings", virtual_host, "e", source, "e", destination),
+                None,
+                None,
+            )
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
     /// Lists all bindings of a specific exchange where it is the source.
     pub async fn list_exchange_bindings_with_source(
         &self,
@@ -555,6 +1176,26 @@ where
         .await
     }
 
+    /// Lists all bindings that have the given queue (or stream) or exchange as their destination.
+    ///
+    /// For queues and streams, this includes the implicit binding to the default (unnamed) exchange.
+    pub async fn list_bindings_with_destination(
+        &self,
+        virtual_host: &str,
+        destination: &str,
+        destination_type: BindingDestinationType,
+    ) -> Result<Vec<responses::BindingInfo>> {
+        match destination_type {
+            BindingDestinationType::Queue => {
+                self.list_queue_bindings(virtual_host, destination).await
+            }
+            BindingDestinationType::Exchange => {
+                self.list_exchange_bindings_with_destination(virtual_host, destination)
+                    .await
+            }
+        }
+    }
+
     /// Lists all consumers across the cluster.
     pub async fn list_consumers(&self) -> Result<Vec<responses::Consumer>> {
         let response = self.http_get("consumers", None, None).await?;
@@ -571,6 +1212,23 @@ where
         Ok(response)
     }
 
+    /// Lists consumers of the given queue.
+    ///
+    /// The HTTP API does not provide a queue-specific endpoint, so this function
+    /// lists all consumers in the virtual host and filters them on the client side.
+    pub async fn list_consumers_of_queue(
+        &self,
+        virtual_host: &str,
+        queue: &str,
+    ) -> Result<Vec<responses::Consumer>> {
+        let consumers = self.list_consumers_in(virtual_host).await?;
+        let result = consumers
+            .into_iter()
+            .filter(|c| c.queue.name == queue)
+            .collect();
+        Ok(result)
+    }
+
     /// Returns information about a cluster node.
     pub async fn get_node_info(&self, name: &str) -> Result<responses::ClusterNode> {
         let response = self.http_get(path!("nodes", name), None, None).await?;
@@ -578,6 +1236,24 @@ where
         Ok(response)
     }
 
+    /// Returns information about a cluster node, including the optional detail sections
+    /// (memory, binary heap, ETS tables) enabled in `flags`.
+    pub async fn get_node_info_with_details(
+        &self,
+        name: &str,
+        flags: NodeInfoFlags,
+    ) -> Result<responses::ClusterNode> {
+        let params = flags.query_params();
+        let path = if params.is_empty() {
+            path!("nodes", name)
+        } else {
+            format!("{}?{}", path!("nodes", name), query_string(&params))
+        };
+        let response = self.http_get(path, None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
     /// Returns information about a cluster node.
     pub async fn get_node_memory_footprint(
         &self,
@@ -590,6 +1266,18 @@ where
         Ok(response)
     }
 
+    /// Returns a breakdown of memory used by individual ETS tables on a cluster node.
+    pub async fn get_node_ets_memory_footprint(
+        &self,
+        name: &str,
+    ) -> Result<responses::NodeEtsMemoryBreakdown> {
+        let response = self
+            .http_get(path!("nodes", name, "memory", "ets"), None, None)
+            .await?;
+        let response: responses::NodeEtsMemoryFootprint = response.json().await?;
+        Ok(response.breakdown)
+    }
+
     /// Returns information about a virtual host.
     pub async fn get_vhost(&self, name: &str) -> Result<responses::VirtualHost> {
         let response = self.http_get(path!("vhosts", name), None, None).await?;
@@ -597,6 +1285,27 @@ where
         Ok(response)
     }
 
+    /// Returns the user this client authenticates as.
+    pub async fn current_user(&self) -> Result<responses::CurrentUser> {
+        let response = self.http_get("whoami", None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Returns true if the user this client authenticates as has the given tag.
+    pub async fn current_user_has_tag(&self, tag: &str) -> Result<bool> {
+        let user = self.current_user().await?;
+        Ok(user.has_tag(tag))
+    }
+
+    /// Returns true if the user this client authenticates as is an administrator.
+    ///
+    /// Useful for checking permissions upfront, before performing
+    /// operations that require the `administrator` tag.
+    pub async fn current_user_is_administrator(&self) -> Result<bool> {
+        self.current_user_has_tag("administrator").await
+    }
+
     /// Returns information about a user in the internal database.
     pub async fn get_user(&self, name: &str) -> Result<responses::User> {
         let response = self.http_get(path!("users", name), None, None).await?;
@@ -617,26 +1326,55 @@ where
         Ok(response)
     }
 
-    /// Returns information about an exchange.
-    pub async fn get_exchange_info(
+    /// Returns the message and consumer counts of a queue or stream.
+    ///
+    /// Unlike [`Client::get_queue_info`], only deserializes the counts, see [`responses::QueueDepth`].
+    pub async fn get_queue_depth(
         &self,
         virtual_host: &str,
         name: &str,
-    ) -> Result<responses::ExchangeInfo> {
+    ) -> Result<responses::QueueDepth> {
         let response = self
-            .http_get(path!("exchanges", virtual_host, name), None, None)
+            .http_get(path!("queues", virtual_host, name), None, None)
             .await?;
         let response = response.json().await?;
         Ok(response)
     }
 
-    /// Creates a virtual host.
-    ///
-    /// See [`VirtualHostParams`]
+    /// Returns information about an exchange.
+    pub async fn get_exchange_info(
+        &self,
+        virtual_host: &str,
+        name: &str,
+    ) -> Result<responses::ExchangeInfo> {
+        let response = self
+            .http_get(path!("exchanges", virtual_host, name), None, None)
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Creates a virtual host.
+    ///
+    /// See [`VirtualHostParams`]
     pub async fn create_vhost(&self, params: &VirtualHostParams<'_>) -> Result<()> {
         self.update_vhost(params).await
     }
 
+    /// Creates a virtual host unless it already exists. Existing virtual hosts are not updated.
+    ///
+    /// Returns `true` if the virtual host was created, and `false` if it already existed.
+    pub async fn create_vhost_if_absent(&self, params: &VirtualHostParams<'_>) -> Result<bool> {
+        match self.get_vhost(params.name).await {
+            Ok(_) => Ok(false),
+            Err(NotFound) => {
+                self.create_vhost(params).await?;
+                Ok(true)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     /// Creates a virtual host or updates metadata of an existing one.
     ///
     /// See [`VirtualHostParams`]
@@ -684,10 +1422,15 @@ where
         Ok(())
     }
 
+    /// Declares an exchange.
+    ///
+    /// If the exchange type is provided by a plugin and the declaration fails the way it does
+    /// when that plugin is not enabled, an [`Error::LikelyMissingPlugin`] is returned.
     pub async fn declare_exchange(&self, vhost: &str, params: &ExchangeParams<'_>) -> Result<()> {
         let _response = self
             .http_put(path!("exchanges", vhost, params.name), params, None, None)
-            .await?;
+            .await
+            .map_err(|e| e.or_likely_missing_plugin(&params.exchange_type))?;
         Ok(())
     }
 
@@ -757,6 +1500,77 @@ where
         Ok(())
     }
 
+    /// Returns a summary of what a virtual host contains: the number of queues and streams,
+    /// exchanges, bindings, connections and messages. Useful to report
+    /// what will be deleted before deleting the virtual host.
+    ///
+    /// Built-in exchanges and the implicit default exchange bindings are not counted.
+    pub async fn vhost_contents_summary(
+        &self,
+        vhost: &str,
+    ) -> Result<responses::VirtualHostContentsSummary> {
+        let queues = self.list_queues_in(vhost).await?;
+        let exchanges = self.list_exchanges_in(vhost).await?;
+        let bindings = self.list_bindings_in(vhost).await?;
+        let connections = self.list_connections_in(vhost).await?;
+
+        Ok(responses::VirtualHostContentsSummary {
+            vhost: vhost.to_owned(),
+            queue_count: queues.len(),
+            exchange_count: exchanges.iter().filter(|x| !x.is_built_in()).count(),
+            binding_count: bindings
+                .iter()
+                .filter(|b| !b.is_default_exchange_binding())
+                .count(),
+            connection_count: connections.len(),
+            message_count: queues.iter().map(|q| q.message_count).sum(),
+        })
+    }
+
+    /// Deletes a virtual host and returns a summary of what was deleted with it:
+    /// the number of connections, queues and streams, exchanges and messages.
+    ///
+    /// The counts are a best-effort snapshot taken just before deletion.
+    /// If the virtual host is protected from deletion, returns
+    /// [`Error::VirtualHostProtectedFromDeletion`] without counting anything.
+    pub async fn delete_vhost_with_report(
+        &self,
+        vhost: &str,
+        idempotently: bool,
+    ) -> Result<responses::VirtualHostDeletionReport> {
+        let mut report = responses::VirtualHostDeletionReport {
+            vhost: vhost.to_owned(),
+            ..Default::default()
+        };
+
+        let vh = match self.get_vhost(vhost).await {
+            Ok(vh) => vh,
+            Err(NotFound) if idempotently => return Ok(report),
+            Err(err) => return Err(err),
+        };
+        if vh.protected_from_deletion {
+            return Err(Error::VirtualHostProtectedFromDeletion {
+                name: vhost.to_owned(),
+            });
+        }
+
+        let queues = self.list_queues_in(vhost).await?;
+        report.connection_count = self.list_connections_in(vhost).await?.len();
+        report.exchange_count = self
+            .list_exchanges_in(vhost)
+            .await?
+            .iter()
+            .filter(|x| !x.is_built_in())
+            .count();
+        report.queue_count = queues.len();
+        report.message_count = queues.iter().map(|q| q.message_count).sum();
+
+        self.delete_vhost(vhost, idempotently).await?;
+        report.deleted = true;
+
+        Ok(report)
+    }
+
     pub async fn delete_user(&self, username: &str, idempotently: bool) -> Result<()> {
         let excludes = if idempotently {
             Some(StatusCode::NOT_FOUND)
@@ -806,6 +1620,34 @@ where
         Ok(())
     }
 
+    /// Deletes multiple queues in a virtual host, performing up to [`DELETE_QUEUES_CONCURRENCY`]
+    /// deletions concurrently.
+    ///
+    /// All deletions are attempted even if some of them fail. The failures are then returned
+    /// as an [`Error::PartialFailure`].
+    pub async fn delete_queues(
+        &self,
+        vhost: &str,
+        names: &[&str],
+        idempotently: bool,
+    ) -> Result<()> {
+        let failures: Vec<(String, HttpClientError)> = stream::iter(names)
+            .map(|name| async move {
+                let result = self.delete_queue(vhost, name, idempotently).await;
+                result.err().map(|e| (name.to_string(), e))
+            })
+            .buffer_unordered(DELETE_QUEUES_CONCURRENCY)
+            .filter_map(|failure| async move { failure })
+            .collect()
+            .await;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PartialFailure { failures })
+        }
+    }
+
     pub async fn delete_exchange(&self, vhost: &str, name: &str, idempotently: bool) -> Result<()> {
         let excludes = if idempotently {
             Some(StatusCode::NOT_FOUND)
@@ -818,6 +1660,96 @@ where
         Ok(())
     }
 
+    /// Deletes multiple exchanges in a virtual host, performing up to [`DELETE_EXCHANGES_CONCURRENCY`]
+    /// deletions concurrently.
+    ///
+    /// All deletions are attempted even if some of them fail. The failures are then returned
+    /// as an [`Error::PartialFailure`].
+    pub async fn delete_exchanges(
+        &self,
+        vhost: &str,
+        names: &[&str],
+        idempotently: bool,
+    ) -> Result<()> {
+        let failures: Vec<(String, HttpClientError)> = stream::iter(names)
+            .map(|name| async move {
+                let result = self.delete_exchange(vhost, name, idempotently).await;
+                result.err().map(|e| (name.to_string(), e))
+            })
+            .buffer_unordered(DELETE_EXCHANGES_CONCURRENCY)
+            .filter_map(|failure| async move { failure })
+            .collect()
+            .await;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PartialFailure { failures })
+        }
+    }
+
+    /// Fetches a binding of a queue (or stream) to an exchange using its properties key
+    /// (see [`responses::BindingInfo::properties_key`]).
+    ///
+    /// Returns [`Error::NotFound`] if there is no such binding.
+    pub async fn get_binding_between_queue_and_exchange(
+        &self,
+        virtual_host: &str,
+        exchange: &str,
+        queue: &str,
+        properties_key: &str,
+    ) -> Result<responses::BindingInfo> {
+        let response = self
+            .http_get(
+                // /api/bindings/vhost/e/exchange/q/queue/props
+                path!(
+                    "bindings",
+                    virtual_host,
+                    "e",
+                    exchange,
+                    "q",
+                    queue,
+                    properties_key
+                ),
+                None,
+                None,
+            )
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Fetches an [exchange-to-exchange binding](https://rabbitmq.com/docs/e2e/) using its properties key
+    /// (see [`responses::BindingInfo::properties_key`]).
+    ///
+    /// Returns [`Error::NotFound`] if there is no such binding.
+    pub async fn get_binding_between_exchanges(
+        &self,
+        virtual_host: &str,
+        source: &str,
+        destination: &str,
+        properties_key: &str,
+    ) -> Result<responses::BindingInfo> {
+        let response = self
+            .http_get(
+                // /api/bindings/vhost/e/source/e/destination/props
+                path!(
+                    "bindings",
+                    virtual_host,
+                    "e",
+                    source,
+                    "e",
+                    destination,
+                    properties_key
+                ),
+                None,
+                None,
+            )
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
     pub async fn delete_binding(
         &self,
         virtual_host: &str,
@@ -831,15 +1763,9 @@ where
 
         // to delete a binding, we need properties, that we can get from the server
         // so we search for the binding before deleting it
-        let bindings = match destination_type {
-            BindingDestinationType::Queue => {
-                self.list_queue_bindings(virtual_host, destination).await?
-            }
-            BindingDestinationType::Exchange => {
-                self.list_exchange_bindings_with_destination(virtual_host, destination)
-                    .await?
-            }
-        };
+        let bindings = self
+            .list_bindings_with_destination(virtual_host, destination, destination_type)
+            .await?;
 
         let bs: Vec<&BindingInfo> = bindings
             .iter()
@@ -848,33 +1774,7 @@ where
         match bs.len() {
             0 => Err(Error::NotFound),
             1 => {
-                let first_key = bs.first().unwrap().properties_key.clone();
-                let path_appreviation = destination_type.path_appreviation();
-                let path = match first_key {
-                    Some(pk) => {
-                        path!(
-                            // /api/bindings/vhost/e/exchange/[eq]/destination/props
-                            "bindings",
-                            virtual_host,
-                            "e",
-                            source,
-                            path_appreviation,
-                            destination,
-                            pk.as_str()
-                        )
-                    }
-                    None => {
-                        path!(
-                            // /api/bindings/vhost/e/exchange/[eq]/destination/
-                            "bindings",
-                            virtual_host,
-                            "e",
-                            source,
-                            path_appreviation,
-                            destination
-                        )
-                    }
-                };
+                let path = binding_path(bs[0]);
                 let response = self.http_delete(&path, None, None).await?;
                 Ok(response)
             }
@@ -882,6 +1782,73 @@ where
         }
     }
 
+    /// Like [`Client::delete_binding`] but returns `Ok(false)` instead of [`Error::NotFound`]
+    /// when no binding matched, and `Ok(true)` when the binding was deleted.
+    ///
+    /// Still returns [`Error::MultipleMatchingBindings`] if more than one binding matched.
+    pub async fn delete_binding_idempotent(
+        &self,
+        virtual_host: &str,
+        source: &str,
+        destination: &str,
+        destination_type: BindingDestinationType,
+        routing_key: &str,
+        arguments: XArguments,
+    ) -> Result<bool> {
+        let result = self
+            .delete_binding(
+                virtual_host,
+                source,
+                destination,
+                destination_type,
+                routing_key,
+                arguments,
+            )
+            .await;
+        match result {
+            Ok(_) => Ok(true),
+            Err(NotFound) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Deletes multiple bindings (e.g. returned by [`Client::list_bindings`]), performing up to
+    /// [`DELETE_BINDINGS_CONCURRENCY`] deletions concurrently. Bindings are identified
+    /// by their properties key, so no lookups are performed.
+    ///
+    /// All deletions are attempted even if some of them fail. The failures are then returned
+    /// as an [`Error::PartialFailure`], with bindings described as `source -> destination`.
+    pub async fn delete_bindings(
+        &self,
+        bindings: &[BindingInfo],
+        idempotently: bool,
+    ) -> Result<()> {
+        let excludes = if idempotently {
+            Some(StatusCode::NOT_FOUND)
+        } else {
+            None
+        };
+        let failures: Vec<(String, HttpClientError)> = stream::iter(bindings)
+            .map(|binding| async move {
+                let result = self
+                    .http_delete(binding_path(binding), excludes, None)
+                    .await;
+                result
+                    .err()
+                    .map(|e| (format!("{} -> {}", binding.source, binding.destination), e))
+            })
+            .buffer_unordered(DELETE_BINDINGS_CONCURRENCY)
+            .filter_map(|failure| async move { failure })
+            .collect()
+            .await;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PartialFailure { failures })
+        }
+    }
+
     pub async fn purge_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
         let _response = self
             .http_delete(path!("queues", virtual_host, name, "contents"), None, None)
@@ -889,6 +1856,98 @@ where
         Ok(())
     }
 
+    /// Purges multiple queues in a virtual host, performing up to [`PURGE_QUEUES_CONCURRENCY`]
+    /// purges concurrently.
+    ///
+    /// Queues that do not exist are reported in [`responses::PurgeReport::not_found`]
+    /// instead of failing the operation. Any other failures are returned
+    /// as an [`Error::PartialPurgeFailure`] that also carries the report.
+    pub async fn purge_queues(
+        &self,
+        vhost: &str,
+        names: &[&str],
+    ) -> Result<responses::PurgeReport> {
+        let results: Vec<(&str, Result<()>)> = stream::iter(names)
+            .map(|name| async move { (*name, self.purge_queue(vhost, name).await) })
+            .buffered(PURGE_QUEUES_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut report = responses::PurgeReport::default();
+        let mut failures = Vec::new();
+        for (name, result) in results {
+            match result {
+                Ok(()) => report.purged.push(name.to_owned()),
+                Err(NotFound) => report.not_found.push(name.to_owned()),
+                Err(e) => failures.push((name.to_owned(), e)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(report)
+        } else {
+            Err(Error::PartialPurgeFailure { report, failures })
+        }
+    }
+
+    /// Adds a replica of a [quorum queue](https://www.rabbitmq.com/docs/quorum-queues#member-management)
+    /// on the given node.
+    ///
+    /// Returns the queue members after the change.
+    pub async fn grow_quorum_queue_membership(
+        &self,
+        virtual_host: &str,
+        name: &str,
+        node: &str,
+    ) -> Result<responses::QuorumMembershipChange> {
+        let _response = self
+            .http_post(
+                path!("queues", "quorum", virtual_host, name, "replicas", "add"),
+                &json!({"node": node}),
+                None,
+                None,
+            )
+            .await?;
+        self.quorum_membership_change(virtual_host, name, node)
+            .await
+    }
+
+    /// Removes the replica of a [quorum queue](https://www.rabbitmq.com/docs/quorum-queues#member-management)
+    /// hosted on the given node.
+    ///
+    /// Returns the queue members after the change.
+    pub async fn shrink_quorum_queue_membership(
+        &self,
+        virtual_host: &str,
+        name: &str,
+        node: &str,
+    ) -> Result<responses::QuorumMembershipChange> {
+        let _response = self
+            .http_delete_with_body(
+                path!("queues", "quorum", virtual_host, name, "replicas", "delete"),
+                &json!({"node": node}),
+                None,
+                None,
+            )
+            .await?;
+        self.quorum_membership_change(virtual_host, name, node)
+            .await
+    }
+
+    /// Triggers [synchronisation](https://www.rabbitmq.com/docs/3.13/ha#unsynchronised-mirrors) of a classic mirrored queue.
+    ///
+    /// If the action is not applicable to the queue (e.g. because of its type), the node will respond
+    /// with a `400 Bad Request`, which is returned as a [`Error::ClientErrorResponse`].
+    pub async fn sync_queue(&self, virtual_host: &str, name: &str) -> Result<()> {
+        self.queue_action(virtual_host, name, "sync").await
+    }
+
+    /// Cancels an in-progress [synchronisation](https://www.rabbitmq.com/docs/3.13/ha#unsynchronised-mirrors)
+    /// of a classic mirrored queue.
+    pub async fn cancel_queue_sync(&self, virtual_host: &str, name: &str) -> Result<()> {
+        self.queue_action(virtual_host, name, "cancel_sync").await
+    }
+
     pub async fn list_runtime_parameters(&self) -> Result<Vec<responses::RuntimeParameter>> {
         let response = self.http_get("parameters", None, None).await?;
         let response = response.json().await?;
@@ -990,6 +2049,34 @@ where
         Ok(())
     }
 
+    /// Sets a user limit, then reads it back to confirm that it was applied.
+    ///
+    /// Returns the confirmed value or [`Error::LimitNotApplied`] if the node
+    /// reports a different value (or no value at all).
+    pub async fn set_user_limit_and_confirm(
+        &self,
+        username: &str,
+        limit: EnforcedLimitParams<UserLimitTarget>,
+    ) -> Result<i64> {
+        let kind = limit.kind.clone();
+        let expected = limit.value;
+        self.set_user_limit(username, limit).await?;
+
+        let key: &str = kind.as_ref();
+        let limits = self.list_user_limits(username).await?;
+        let actual = limits
+            .iter()
+            .find_map(|l| l.limits.get(key).and_then(|v| v.as_i64()));
+        match actual {
+            Some(val) if val == expected => Ok(val),
+            _ => Err(Error::LimitNotApplied {
+                kind: key.to_owned(),
+                expected,
+                actual,
+            }),
+        }
+    }
+
     pub async fn clear_user_limit(&self, username: &str, kind: UserLimitTarget) -> Result<()> {
         let _response = self
             .http_delete(path!("user-limits", username, kind), None, None)
@@ -1011,6 +2098,16 @@ where
         Ok(response)
     }
 
+    /// Returns the limits of a user in a form that does not require scanning
+    /// the result of [`Client::list_user_limits`].
+    pub async fn get_user_effective_limits(
+        &self,
+        username: &str,
+    ) -> Result<responses::EffectiveUserLimits> {
+        let limits = self.list_user_limits(username).await?;
+        Ok(responses::EffectiveUserLimits::from_limits(&limits))
+    }
+
     pub async fn set_vhost_limit(
         &self,
         vhost: &str,
@@ -1023,6 +2120,44 @@ where
         Ok(())
     }
 
+    /// Sets a virtual host limit, then reads it back to confirm that it was applied.
+    ///
+    /// Returns the confirmed value or [`Error::LimitNotApplied`] if the node
+    /// reports a different value (or no value at all).
+    pub async fn set_vhost_limit_and_confirm(
+        &self,
+        vhost: &str,
+        limit: EnforcedLimitParams<VirtualHostLimitTarget>,
+    ) -> Result<i64> {
+        let kind = limit.kind.clone();
+        let expected = limit.value;
+        self.set_vhost_limit(vhost, limit).await?;
+
+        let key: &str = kind.as_ref();
+        let limits = self.list_vhost_limits(vhost).await?;
+        let actual = limits
+            .iter()
+            .find_map(|l| l.limits.get(key).and_then(|v| v.as_i64()));
+        match actual {
+            Some(val) if val == expected => Ok(val),
+            _ => Err(Error::LimitNotApplied {
+                kind: key.to_owned(),
+                expected,
+                actual,
+            }),
+        }
+    }
+
+    /// Returns the limits of a virtual host in a form that does not require scanning
+    /// the result of [`Client::list_vhost_limits`].
+    pub async fn get_vhost_effective_limits(
+        &self,
+        vhost: &str,
+    ) -> Result<responses::EffectiveVhostLimits> {
+        let limits = self.list_vhost_limits(vhost).await?;
+        Ok(responses::EffectiveVhostLimits::from_limits(&limits))
+    }
+
     pub async fn clear_vhost_limit(&self, vhost: &str, kind: VirtualHostLimitTarget) -> Result<()> {
         let _response = self
             .http_delete(
@@ -1083,6 +2218,31 @@ where
         Ok(response)
     }
 
+    /// Returns the policy that would apply to a queue (or stream) with the given name:
+    /// the highest priority policy that applies to queues and whose pattern matches the name.
+    /// If several matching policies have the same priority, the first one listed is returned.
+    ///
+    /// The matching is performed on the client side, for preview and audit purposes.
+    /// Since only the name is known, policies that target specific queue types
+    /// (e.g. quorum queues) are considered to match any queue.
+    /// Patterns are evaluated as PCRE-compatible regular expressions, like on the server.
+    /// If a pattern cannot be evaluated, an [`Error::InvalidRegex`] is returned
+    /// instead of a possibly incorrect result.
+    pub async fn effective_policy_for_queue(
+        &self,
+        vhost: &str,
+        queue_name: &str,
+    ) -> Result<Option<responses::Policy>> {
+        let policies = self.list_policies_in(vhost).await?;
+        let mut matching = Vec::new();
+        for p in policies {
+            if p.apply_to.includes_queues() && regex_matches(&p.pattern, queue_name)? {
+                matching.push(p);
+            }
+        }
+        Ok(matching.into_iter().min_by_key(|p| Reverse(p.priority)))
+    }
+
     pub async fn declare_policy(&self, params: &PolicyParams<'_>) -> Result<()> {
         let _response = self
             .http_put(
@@ -1181,6 +2341,53 @@ where
         Ok(response)
     }
 
+    /// Lists all [topic permissions](https://rabbitmq.com/docs/access-control#topic-authorisation) in the cluster.
+    pub async fn list_topic_permissions(&self) -> Result<Vec<responses::TopicPermission>> {
+        let response = self.http_get("topic-permissions", None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Lists topic permissions in the given virtual host.
+    pub async fn list_topic_permissions_in(
+        &self,
+        vhost: &str,
+    ) -> Result<Vec<responses::TopicPermission>> {
+        let response = self
+            .http_get(path!("vhosts", vhost, "topic-permissions"), None, None)
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Lists topic permissions of the given user.
+    pub async fn list_topic_permissions_of(
+        &self,
+        user: &str,
+    ) -> Result<Vec<responses::TopicPermission>> {
+        let response = self
+            .http_get(path!("users", user, "topic-permissions"), None, None)
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Returns the topic permissions of a user in a virtual host.
+    ///
+    /// The endpoint responds with a list (one entry per exchange), this function
+    /// returns its first element, or [`Error::NotFound`] if the list is empty.
+    pub async fn get_topic_permissions_of(
+        &self,
+        vhost: &str,
+        user: &str,
+    ) -> Result<responses::TopicPermission> {
+        let response = self
+            .http_get(path!("topic-permissions", vhost, user), None, None)
+            .await?;
+        let response: Vec<responses::TopicPermission> = response.json().await?;
+        response.into_iter().next().ok_or(NotFound)
+    }
+
     //
     // Rebalancing
     //
@@ -1191,6 +2398,46 @@ where
         Ok(())
     }
 
+    /// Triggers a queue leader rebalancing and waits for it to complete, up to `timeout`.
+    ///
+    /// The broker does not provide an explicit completion signal for rebalancing,
+    /// so it is considered complete when leader placement (the number of leaders on each node)
+    /// is the same across two consecutive polls. Polling happens every [`REBALANCE_POLL_INTERVAL`].
+    ///
+    /// If the timeout elapses first, the result will have `stabilized` set to `false`.
+    pub async fn rebalance_queue_leaders_and_wait(
+        &self,
+        timeout: Duration,
+    ) -> Result<responses::RebalanceResult> {
+        let started_at = Instant::now();
+        let before = responses::queue_leader_distribution(&self.list_queues().await?);
+        self.rebalance_queue_leaders().await?;
+
+        let mut previous: Option<responses::QueueLeaderDistribution> = None;
+        loop {
+            let remaining = timeout.saturating_sub(started_at.elapsed());
+            if remaining.is_zero() {
+                return Ok(responses::RebalanceResult {
+                    after: previous.unwrap_or_else(|| before.clone()),
+                    before,
+                    stabilized: false,
+                });
+            }
+            let interval = REBALANCE_POLL_INTERVAL.min(remaining);
+            tokio::time::sleep(interval).await;
+
+            let current = responses::queue_leader_distribution(&self.list_queues().await?);
+            if previous.as_ref() == Some(&current) {
+                return Ok(responses::RebalanceResult {
+                    before,
+                    after: current,
+                    stabilized: true,
+                });
+            }
+            previous = Some(current);
+        }
+    }
+
     //
     // Definitions
 
@@ -1204,23 +2451,268 @@ where
         Ok(response)
     }
 
+    /// Exports cluster-wide definitions as a JSON document, using the given timeout
+    /// instead of the default one configured with [`ClientBuilder::with_default_timeout`].
+    ///
+    /// Definition exports of large clusters can take considerably longer than most other operations.
+    /// The given timeout overrides the timeout configured on the `HttpClient` used by this client, if any.
+    pub async fn export_cluster_wide_definitions_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<String> {
+        let response = self
+            .http_get_with_timeout("definitions", Some(timeout), None, None)
+            .await?;
+        let response = response.text().await?;
+        Ok(response)
+    }
+
+    /// Exports cluster-wide definitions as a JSON document, writing the response body
+    /// to the provided writer chunk by chunk instead of buffering it in memory.
+    ///
+    /// Returns the number of bytes written.
+    pub async fn export_cluster_wide_definitions_to_writer<W: AsyncWrite + Unpin>(
+        &self,
+        w: &mut W,
+    ) -> Result<u64> {
+        let mut response = self.http_get("definitions", None, None).await?;
+        let mut n: u64 = 0;
+        while let Some(chunk) = response.chunk().await? {
+            w.write_all(&chunk).await?;
+            n += chunk.len() as u64;
+        }
+        w.flush().await?;
+        Ok(n)
+    }
+
     pub async fn export_definitions_as_data(&self) -> Result<DefinitionSet> {
         let response = self.http_get("definitions", None, None).await?;
         let response = response.json().await?;
         Ok(response)
     }
 
+    /// Compares a definition set (the intended state) with the queues, exchanges and bindings
+    /// that currently exist in the cluster, and reports the drift.
+    ///
+    /// Only the virtual hosts declared or referenced by the definition set are compared,
+    /// see [`responses::ReconciliationReport`] for details.
+    pub async fn reconcile_against_definitions(
+        &self,
+        definitions: &DefinitionSet,
+    ) -> Result<responses::ReconciliationReport> {
+        let queues = self.list_queues().await?;
+        let exchanges = self.list_exchanges().await?;
+        let bindings = self.list_bindings().await?;
+        Ok(responses::ReconciliationReport::compute(
+            definitions,
+            queues,
+            exchanges,
+            bindings,
+        ))
+    }
+
+    /// Compares the incoming definition set with the current cluster definitions
+    /// and reports which queues, exchanges and policies an import would create
+    /// and which ones it would overwrite. Nothing is imported.
+    pub async fn plan_definition_import(
+        &self,
+        incoming: &DefinitionSet,
+    ) -> Result<responses::DefinitionImportPlan> {
+        let current = self.export_definitions_as_data().await?;
+        Ok(responses::DefinitionImportPlan::compute(incoming, &current))
+    }
+
     pub async fn import_definitions(&self, definitions: Value) -> Result<()> {
         self.http_post("definitions", &definitions, None, None)
             .await?;
         Ok(())
     }
 
-    //
-    // Health Checks
-    //
-
-    pub async fn health_check_cluster_wide_alarms(&self) -> Result<()> {
+    /// Imports cluster-wide definitions from a JSON document.
+    ///
+    /// The document is parsed and checked before being sent to the node: malformed
+    /// JSON or a document without any of the well-known top-level keys (`vhosts`, `users`, `queues`,
+    /// `exchanges`, `bindings`) will result in an [`Error::InvalidDefinitions`].
+    pub async fn import_cluster_wide_definitions_from_string(
+        &self,
+        definitions: &str,
+    ) -> Result<()> {
+        let definitions = parse_definitions(definitions)?;
+        self.http_post("definitions", &definitions, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Imports definitions of a single virtual host from a JSON document.
+    ///
+    /// See [`Client::import_cluster_wide_definitions_from_string`] for how the document is validated.
+    pub async fn import_vhost_definitions_from_string(
+        &self,
+        vhost: &str,
+        definitions: &str,
+    ) -> Result<()> {
+        let definitions = parse_definitions(definitions)?;
+        self.http_post(path!("definitions", vhost), &definitions, None, None)
+            .await?;
+        Ok(())
+    }
+
+    //
+    // Federation
+    //
+
+    /// Lists [federation upstreams](https://rabbitmq.com/docs/federation-reference/#upstreams)
+    /// in the cluster.
+    ///
+    /// Upstream parameters that cannot be parsed are skipped.
+    /// Use [`Client::list_federation_upstreams_lenient`] to find out which ones.
+    pub async fn list_federation_upstreams(&self) -> Result<Vec<responses::FederationUpstream>> {
+        let (upstreams, _failures) = self.list_federation_upstreams_lenient().await?;
+        Ok(upstreams)
+    }
+
+    /// Lists [federation upstreams](https://rabbitmq.com/docs/federation-reference/#upstreams)
+    /// in the cluster, along with the names of upstream parameters that could not be parsed
+    /// and the parsing errors.
+    pub async fn list_federation_upstreams_lenient(
+        &self,
+    ) -> Result<responses::FederationUpstreamsWithFailures> {
+        let params = self
+            .list_runtime_parameters_of_component(requests::FEDERATION_UPSTREAM_COMPONENT)
+            .await?;
+        let mut upstreams = Vec::new();
+        let mut failures = Vec::new();
+        for param in params {
+            let name = param.name.clone();
+            match responses::FederationUpstream::try_from(param) {
+                Ok(upstream) => upstreams.push(upstream),
+                Err(e) => failures.push((name, e)),
+            }
+        }
+        Ok((upstreams, failures))
+    }
+
+    /// Lists all [federation](https://www.rabbitmq.com/docs/federation) links in the cluster.
+    pub async fn list_federation_links(&self) -> Result<Vec<responses::FederationLink>> {
+        let response = self.http_get("federation-links", None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Lists [federation](https://www.rabbitmq.com/docs/federation) links in the given virtual host.
+    pub async fn list_federation_links_in(
+        &self,
+        vhost: &str,
+    ) -> Result<Vec<responses::FederationLink>> {
+        let response = self
+            .http_get(path!("federation-links", vhost), None, None)
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Returns the status of a [federation](https://www.rabbitmq.com/docs/federation) link
+    /// of the given upstream in the given virtual host. If the upstream has multiple links
+    /// (e.g. it is used by several federated exchanges), the status of the first one is returned.
+    ///
+    /// Returns [`Error::NotFound`] if there is no such link.
+    pub async fn federation_link_status(
+        &self,
+        vhost: &str,
+        upstream: &str,
+    ) -> Result<responses::FederationLinkStatus> {
+        let links = self.list_federation_links_in(vhost).await?;
+        links
+            .into_iter()
+            .find(|l| l.upstream == upstream)
+            .map(|l| l.status)
+            .ok_or(NotFound)
+    }
+
+    //
+    // Shovels
+    //
+
+    /// Lists all [shovels](https://www.rabbitmq.com/docs/shovel) in the cluster, both static and dynamic ones.
+    pub async fn list_shovels(&self) -> Result<Vec<responses::Shovel>> {
+        let response = self.http_get("shovels", None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Lists [shovels](https://www.rabbitmq.com/docs/shovel) in the given virtual host.
+    pub async fn list_shovels_in(&self, vhost: &str) -> Result<Vec<responses::Shovel>> {
+        let response = self.http_get(path!("shovels", vhost), None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    /// Returns a [shovel](https://www.rabbitmq.com/docs/shovel) in the given virtual host.
+    ///
+    /// Returns [`Error::NotFound`] if there is no such shovel.
+    pub async fn get_shovel(&self, vhost: &str, name: &str) -> Result<responses::Shovel> {
+        let shovels = self.list_shovels_in(vhost).await?;
+        shovels.into_iter().find(|s| s.name == name).ok_or(NotFound)
+    }
+
+    /// Returns the state of a [shovel](https://www.rabbitmq.com/docs/shovel),
+    /// e.g. [`responses::ShovelState::Running`].
+    ///
+    /// Returns [`Error::NotFound`] if there is no such shovel.
+    pub async fn shovel_state(&self, vhost: &str, name: &str) -> Result<responses::ShovelState> {
+        let shovel = self.get_shovel(vhost, name).await?;
+        Ok(shovel.state)
+    }
+
+    /// Restarts a [dynamic shovel](https://www.rabbitmq.com/docs/shovel-dynamic).
+    /// Unlike [`Client::delete_shovel`], this keeps the shovel definition in place.
+    ///
+    /// Returns [`Error::NotFound`] if there is no such shovel.
+    pub async fn restart_shovel(&self, vhost: &str, name: &str) -> Result<()> {
+        let _response = self
+            .http_delete(
+                path!("shovels", "vhost", vhost, name, "restart"),
+                None,
+                None,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Declares a [dynamic shovel](https://www.rabbitmq.com/docs/shovel-dynamic) that uses AMQP 0-9-1
+    /// for both its source and destination.
+    ///
+    /// The parameters are validated with [`Amqp091ShovelParams::validate`] before the request is sent,
+    /// invalid ones result in an [`Error::InvalidShovelDefinition`].
+    pub async fn declare_amqp091_shovel(&self, params: Amqp091ShovelParams<'_>) -> Result<()> {
+        params.validate()?;
+        let definition = RuntimeParameterDefinition::from(params);
+        self.upsert_runtime_parameter(&definition).await
+    }
+
+    /// Declares a [dynamic shovel](https://www.rabbitmq.com/docs/shovel-dynamic) that uses AMQP 1.0
+    /// for both its source and destination.
+    ///
+    /// The parameters are validated with [`Amqp10ShovelParams::validate`] before the request is sent,
+    /// invalid ones result in an [`Error::InvalidShovelDefinition`].
+    pub async fn declare_amqp10_shovel(&self, params: Amqp10ShovelParams<'_>) -> Result<()> {
+        params.validate()?;
+        let definition = RuntimeParameterDefinition::from(params);
+        self.upsert_runtime_parameter(&definition).await
+    }
+
+    /// Deletes a [dynamic shovel](https://www.rabbitmq.com/docs/shovel-dynamic) by clearing
+    /// the runtime parameter that defines it.
+    pub async fn delete_shovel(&self, vhost: &str, name: &str) -> Result<()> {
+        self.clear_runtime_parameter(SHOVEL_COMPONENT, vhost, name)
+            .await
+    }
+
+    //
+    // Health Checks
+    //
+
+    pub async fn health_check_cluster_wide_alarms(&self) -> Result<()> {
         self.health_check_alarms("health/checks/alarms").await
     }
 
@@ -1233,6 +2725,28 @@ where
         self.boolean_health_check(path).await
     }
 
+    /// Checks if the node is [quorum critical](https://www.rabbitmq.com/docs/monitoring#health-checks),
+    /// that is, if shutting it down would make some quorum queues or streams lose their quorum.
+    ///
+    /// Unlike [`Client::health_check_if_node_is_quorum_critical`], a critical node is reported
+    /// as a [`responses::QuorumCriticalityReport`] and not an error.
+    pub async fn node_is_quorum_critical(&self) -> Result<responses::QuorumCriticalityReport> {
+        // a critical node is reported with a 503, which is expected here
+        let response = self
+            .http_get(
+                "health/checks/node-is-quorum-critical",
+                None,
+                Some(StatusCode::SERVICE_UNAVAILABLE),
+            )
+            .await?;
+        if response.status().is_success() {
+            return Ok(responses::QuorumCriticalityReport::default());
+        }
+
+        let details: responses::QuorumCriticalityCheckDetails = response.json().await?;
+        Ok(details.into())
+    }
+
     pub async fn health_check_port_listener(&self, port: u16) -> Result<()> {
         let port_s = port.to_string();
         let path = path!("health", "checks", "port-listener", port_s);
@@ -1245,6 +2759,79 @@ where
         self.boolean_health_check(&path).await
     }
 
+    /// Fails if any TLS-enabled listener has a certificate that expires within the given period.
+    /// The failure details ([`responses::CertificateExpirationCheckDetails`]) list the expiring listeners.
+    ///
+    /// Requires RabbitMQ 3.8.10 or later.
+    pub async fn health_check_certificate_expiration(
+        &self,
+        within: u32,
+        unit: TimeUnit,
+    ) -> Result<()> {
+        let within_s = within.to_string();
+        let path = path!("health", "checks", "certificate-expiration", within_s, unit);
+        self.boolean_health_check(&path).await
+    }
+
+    /// Fails if the node is not in service, for example, because it is in maintenance mode
+    /// or is shutting down.
+    ///
+    /// Requires RabbitMQ 3.13 or later.
+    pub async fn health_check_is_in_service(&self) -> Result<()> {
+        let path = "health/checks/is-in-service";
+        self.boolean_health_check(path).await
+    }
+
+    /// Fails if the node's metadata store (Khepri or Mnesia) has not finished initializing.
+    /// The failure details ([`responses::MetadataStoreInitializationCheckDetails`]) report
+    /// which metadata store is used.
+    ///
+    /// Requires RabbitMQ 3.13 or later.
+    pub async fn health_check_metadata_store_initialized(&self) -> Result<()> {
+        let path = "health/checks/metadata-store/initialized";
+        self.boolean_health_check(path).await
+    }
+
+    /// Fails if the node has `limit` or more client connections. The failure details
+    /// ([`responses::NodeConnectionLimitCheckDetails`]) include the current number of connections.
+    ///
+    /// Requires RabbitMQ 3.13 or later.
+    pub async fn health_check_below_connection_limit(&self, limit: u32) -> Result<()> {
+        let limit_s = limit.to_string();
+        let path = path!("health", "checks", "below-node-connection-limit", limit_s);
+        self.boolean_health_check(&path).await
+    }
+
+    /// Performs an [aliveness test](https://www.rabbitmq.com/docs/monitoring#health-checks) in the given virtual host:
+    /// declares a temporary queue, publishes a message to it and consumes it.
+    ///
+    /// Any response other than `{"status": "ok"}` will be returned as an [`Error::AlivenessCheckFailed`].
+    pub async fn aliveness_test(&self, vhost: &str) -> Result<()> {
+        // a failed test is reported with a 503, which we'd like to report using a custom error type
+        let response = self
+            .http_get(
+                path!("aliveness-test", vhost),
+                None,
+                Some(StatusCode::SERVICE_UNAVAILABLE),
+            )
+            .await?;
+        let status_code = response.status();
+        let body = response.text().await?;
+
+        let is_ok = status_code.is_success()
+            && serde_json::from_str::<Value>(&body)
+                .map(|v| v.get("status").and_then(|s| s.as_str()) == Some("ok"))
+                .unwrap_or(false);
+        if is_ok {
+            return Ok(());
+        }
+
+        Err(Error::AlivenessCheckFailed {
+            vhost: vhost.to_owned(),
+            body,
+        })
+    }
+
     async fn boolean_health_check(&self, path: &str) -> std::result::Result<(), HttpClientError> {
         // we expect that StatusCode::SERVICE_UNAVAILABLE may be return and ignore
         // it here to provide a custom error type later
@@ -1269,6 +2856,9 @@ where
     // Publish and consume messages
     //
 
+    /// Publishes a message via the HTTP API. The returned [`responses::MessageRouted`]
+    /// only indicates whether the message was routed to at least one queue or stream,
+    /// not whether it was delivered to or consumed by any consumers.
     pub async fn publish_message(
         &self,
         vhost: &str,
@@ -1277,23 +2867,61 @@ where
         payload: &str,
         properties: requests::MessageProperties,
     ) -> Result<responses::MessageRouted> {
-        let body = serde_json::json!({
-          "routing_key": routing_key,
-          "payload": payload,
-          "payload_encoding": "string",
-          "properties": properties,
-        });
+        self.publish_message_with_encoding(
+            vhost,
+            exchange,
+            routing_key,
+            payload,
+            "string",
+            properties,
+        )
+        .await
+    }
 
-        let response = self
-            .http_post(
-                path!("exchanges", vhost, exchange, "publish"),
-                &body,
-                None,
-                None,
-            )
+    /// Like [`Client::publish_message`] but for arbitrary binary payloads
+    /// (e.g. Protocol Buffers-encoded messages), which are Base64-encoded for transfer.
+    ///
+    /// Use [`responses::GetMessage::decoded_payload`] to decode payloads of consumed messages.
+    pub async fn publish_message_bytes(
+        &self,
+        vhost: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &[u8],
+        properties: requests::MessageProperties,
+    ) -> Result<responses::MessageRouted> {
+        let encoded = rbase64::encode(payload);
+        self.publish_message_with_encoding(
+            vhost,
+            exchange,
+            routing_key,
+            &encoded,
+            "base64",
+            properties,
+        )
+        .await
+    }
+
+    /// Like [`Client::publish_message`] but returns [`Error::MessageNotRouted`]
+    /// if the message was not routed to any queues or streams.
+    ///
+    /// This only reflects routing, not delivery to or consumption by any consumers.
+    pub async fn publish_message_expecting_route(
+        &self,
+        vhost: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &str,
+        properties: requests::MessageProperties,
+    ) -> Result<responses::MessageRouted> {
+        let result = self
+            .publish_message(vhost, exchange, routing_key, payload, properties)
             .await?;
-        let response = response.json().await?;
-        Ok(response)
+        if result.was_routed() {
+            Ok(result)
+        } else {
+            Err(Error::MessageNotRouted)
+        }
     }
 
     pub async fn get_messages(
@@ -1322,6 +2950,22 @@ where
         Ok(response)
     }
 
+    /// Returns a summary of what the target node supports (e.g. which endpoints are available),
+    /// derived from the versions and product information reported by [`Client::overview`].
+    pub async fn server_capabilities(&self) -> Result<responses::ServerCapabilities> {
+        let overview = self.overview().await?;
+        Ok(responses::ServerCapabilities::from(&overview))
+    }
+
+    /// Returns true if the management plugin collects rates (e.g. `messages_details.rate`).
+    ///
+    /// When the [rates mode](https://rabbitmq.com/docs/management#statistics-interval) is `none`,
+    /// all rates will be reported as zero, so tools that chart rates should check this first.
+    pub async fn rates_available(&self) -> Result<bool> {
+        let overview = self.overview().await?;
+        Ok(overview.rates_available())
+    }
+
     //
     // Feature flags
     //
@@ -1348,6 +2992,38 @@ where
         Ok(())
     }
 
+    /// Enables the given feature flags, one by one.
+    ///
+    /// All flags are attempted even if enabling some of them fails. The failures
+    /// are then returned as an [`Error::PartialFailure`].
+    pub async fn enable_feature_flags(&self, names: &[&str]) -> Result<()> {
+        let mut failures = Vec::new();
+        for name in names {
+            if let Err(e) = self.enable_feature_flag(name).await {
+                failures.push((name.to_string(), e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PartialFailure { failures })
+        }
+    }
+
+    /// Returns `true` if the feature flag is enabled.
+    ///
+    /// Returns [`Error::NotFound`] if the feature flag is not known to the node.
+    pub async fn is_feature_flag_enabled(&self, name: &str) -> Result<bool> {
+        let flags = self.list_feature_flags().await?;
+        flags
+            .0
+            .iter()
+            .find(|ff| ff.name == name)
+            .map(|ff| ff.state == FeatureFlagState::Enabled)
+            .ok_or(NotFound)
+    }
+
     /// Enables all stable feature flags.
     /// This function is idempotent: enabling an already enabled feature flag
     /// will succeed.
@@ -1431,6 +3107,91 @@ where
         Ok(response)
     }
 
+    async fn queue_action(&self, virtual_host: &str, name: &str, action: &str) -> Result<()> {
+        let _response = self
+            .http_post(
+                path!("queues", virtual_host, name, "actions"),
+                &json!({"action": action}),
+                None,
+                None,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn publish_message_with_encoding(
+        &self,
+        vhost: &str,
+        exchange: &str,
+        routing_key: &str,
+        payload: &str,
+        payload_encoding: &str,
+        properties: requests::MessageProperties,
+    ) -> Result<responses::MessageRouted> {
+        let body = serde_json::json!({
+          "routing_key": routing_key,
+          "payload": payload,
+          "payload_encoding": payload_encoding,
+          "properties": properties,
+        });
+
+        let response = self
+            .http_post(
+                path!("exchanges", vhost, exchange, "publish"),
+                &body,
+                None,
+                None,
+            )
+            .await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
+    async fn quorum_membership_change(
+        &self,
+        virtual_host: &str,
+        name: &str,
+        node: &str,
+    ) -> Result<responses::QuorumMembershipChange> {
+        let info = self.get_queue_info(virtual_host, name).await?;
+        Ok(responses::QuorumMembershipChange {
+            vhost: virtual_host.to_owned(),
+            queue: name.to_owned(),
+            node: node.to_owned(),
+            members: info.members.unwrap_or(responses::NodeList(Vec::new())),
+        })
+    }
+
+    async fn get_queue_page(
+        &self,
+        base: &str,
+        page: u32,
+        page_size: u32,
+        filter: Option<&QueuePageFilter<'_>>,
+    ) -> Result<responses::Page<responses::QueueInfo>> {
+        let mut params = vec![
+            ("page", page.to_string()),
+            ("page_size", page_size.to_string()),
+        ];
+        if let Some(f) = filter {
+            if f.use_regex {
+                if let Err(error) = Regex::new(f.name) {
+                    return Err(Error::InvalidRegex {
+                        pattern: f.name.to_owned(),
+                        error,
+                    });
+                }
+            }
+            params.push(("name", f.name.to_owned()));
+            params.push(("use_regex", f.use_regex.to_string()));
+        }
+        let path = format!("{}?{}", base, query_string(&params));
+
+        let response = self.http_get(path, None, None).await?;
+        let response = response.json().await?;
+        Ok(response)
+    }
+
     async fn http_get<S>(
         &self,
         path: S,
@@ -1440,20 +3201,39 @@ where
     where
         S: AsRef<str>,
     {
-        let response = self
-            .client
-            .get(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
+        self.http_get_with_timeout(
+            path,
+            self.request_timeout,
+            client_code_to_accept_or_ignore,
+            server_code_to_accept_or_ignore,
+        )
+        .await
+    }
+
+    async fn http_get_with_timeout<S>(
+        &self,
+        path: S,
+        timeout: Option<Duration>,
+        client_code_to_accept_or_ignore: Option<StatusCode>,
+        server_code_to_accept_or_ignore: Option<StatusCode>,
+    ) -> Result<HttpClientResponse>
+    where
+        S: AsRef<str>,
+    {
+        let url = self.rooted_path(path);
+        let url = url.as_str();
+        self.with_retries(true, || async move {
+            let request = self.client.get(url);
+            let response = self.prepare_request(request, timeout).send().await?;
+            self.ok_or_status_code_error(
+                Method::GET,
                 response,
                 client_code_to_accept_or_ignore,
                 server_code_to_accept_or_ignore,
             )
-            .await?;
-        Ok(response)
+            .await
+        })
+        .await
     }
 
     async fn http_put<S, T>(
@@ -1467,21 +3247,23 @@ where
         S: AsRef<str>,
         T: Serialize,
     {
-        let response = self
-            .client
-            .put(self.rooted_path(path))
-            .json(&payload)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
+        let url = self.rooted_path(path);
+        let url = url.as_str();
+        self.with_retries(true, || async move {
+            let request = self.client.put(url).json(payload);
+            let response = self
+                .prepare_request(request, self.request_timeout)
+                .send()
+                .await?;
+            self.ok_or_status_code_error(
+                Method::PUT,
                 response,
                 client_code_to_accept_or_ignore,
                 server_code_to_accept_or_ignore,
             )
-            .await?;
-        Ok(response)
+            .await
+        })
+        .await
     }
 
     async fn http_post<S, T>(
@@ -1495,21 +3277,23 @@ where
         S: AsRef<str>,
         T: Serialize,
     {
-        let response = self
-            .client
-            .post(self.rooted_path(path))
-            .json(&payload)
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
+        let url = self.rooted_path(path);
+        let url = url.as_str();
+        self.with_retries(false, || async move {
+            let request = self.client.post(url).json(payload);
+            let response = self
+                .prepare_request(request, self.request_timeout)
+                .send()
+                .await?;
+            self.ok_or_status_code_error(
+                Method::POST,
                 response,
                 client_code_to_accept_or_ignore,
                 server_code_to_accept_or_ignore,
             )
-            .await?;
-        Ok(response)
+            .await
+        })
+        .await
     }
 
     async fn http_delete<S>(
@@ -1521,20 +3305,53 @@ where
     where
         S: AsRef<str>,
     {
-        let response = self
-            .client
-            .delete(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
+        let url = self.rooted_path(path);
+        let url = url.as_str();
+        self.with_retries(true, || async move {
+            let request = self.client.delete(url);
+            let response = self
+                .prepare_request(request, self.request_timeout)
+                .send()
+                .await?;
+            self.ok_or_status_code_error(
+                Method::DELETE,
                 response,
                 client_code_to_accept_or_ignore,
                 server_code_to_accept_or_ignore,
             )
-            .await?;
-        Ok(response)
+            .await
+        })
+        .await
+    }
+
+    async fn http_delete_with_body<S, T>(
+        &self,
+        path: S,
+        payload: &T,
+        client_code_to_accept_or_ignore: Option<StatusCode>,
+        server_code_to_accept_or_ignore: Option<StatusCode>,
+    ) -> Result<HttpClientResponse>
+    where
+        S: AsRef<str>,
+        T: Serialize,
+    {
+        let url = self.rooted_path(path);
+        let url = url.as_str();
+        self.with_retries(true, || async move {
+            let request = self.client.delete(url).json(payload);
+            let response = self
+                .prepare_request(request, self.request_timeout)
+                .send()
+                .await?;
+            self.ok_or_status_code_error(
+                Method::DELETE,
+                response,
+                client_code_to_accept_or_ignore,
+                server_code_to_accept_or_ignore,
+            )
+            .await
+        })
+        .await
     }
 
     async fn http_delete_with_headers<S>(
@@ -1547,30 +3364,84 @@ where
     where
         S: AsRef<str>,
     {
-        let response = self
-            .client
-            .delete(self.rooted_path(path))
-            .basic_auth(&self.username, Some(&self.password))
-            .headers(headers)
-            .send()
-            .await?;
-        let response = self
-            .ok_or_status_code_error(
+        let url = self.rooted_path(path);
+        let url = url.as_str();
+        let headers = &headers;
+        self.with_retries(true, || async move {
+            let request = self.client.delete(url).headers(headers.clone());
+            let response = self
+                .prepare_request(request, self.request_timeout)
+                .send()
+                .await?;
+            self.ok_or_status_code_error(
+                Method::DELETE,
                 response,
                 client_code_to_accept_or_ignore,
                 server_code_to_accept_or_ignore,
             )
-            .await?;
-        Ok(response)
+            .await
+        })
+        .await
+    }
+
+    /// Sets the authorization and user agent headers and, if configured, the request timeout.
+    fn prepare_request(
+        &self,
+        request: RequestBuilder,
+        timeout: Option<Duration>,
+    ) -> RequestBuilder {
+        // basic_auth and bearer_auth mark the header as sensitive, so that
+        // it is not included into debug output
+        let request = match &self.oauth2_token {
+            Some(token) => request.bearer_auth(token),
+            None => request.basic_auth(&self.username, Some(&self.password)),
+        };
+        let request = request.header(USER_AGENT, self.user_agent.as_str());
+        match timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
+    }
+
+    /// Performs a request, retrying it according to the configured [`RetryPolicy`].
+    async fn with_retries<F, Fut>(&self, idempotent: bool, request: F) -> Result<HttpClientResponse>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<HttpClientResponse>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match request().await {
+                Err(error) if self.retry_policy.should_retry(&error, attempt, idempotent) => {
+                    tokio::time::sleep(self.retry_policy.delay_after(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
     async fn ok_or_status_code_error(
         &self,
+        method: Method,
         response: HttpClientResponse,
         client_code_to_accept_or_ignore: Option<StatusCode>,
         server_code_to_accept_or_ignore: Option<StatusCode>,
     ) -> Result<HttpClientResponse> {
         let status = response.status();
+
+        // a proxy or a node without the management plugin enabled can respond with e.g. an HTML page
+        let has_body = response.content_length() != Some(0);
+        if has_body && (status.is_success() || status == StatusCode::NOT_FOUND) {
+            if let Some(content_type) = non_json_content_type(response.headers()) {
+                let body = response.text().await?;
+                return Err(Error::UnexpectedContentType {
+                    content_type,
+                    body_prefix: body.chars().take(UNEXPECTED_BODY_PREFIX_LENGTH).collect(),
+                });
+            }
+        }
+
         if status == StatusCode::NOT_FOUND {
             return Err(NotFound);
         }
@@ -1585,6 +3456,7 @@ where
                     // so we copy the key parts into the error first
                     let body = response.text().await?;
                     return Err(ClientErrorResponse {
+                        method: Some(method),
                         url: Some(url),
                         body: Some(body),
                         headers: Some(headers),
@@ -1605,6 +3477,7 @@ where
                     // so we copy the key parts into the error first
                     let body = response.text().await?;
                     return Err(ServerErrorResponse {
+                        method: Some(method),
                         url: Some(url),
                         body: Some(body),
                         headers: Some(headers),
@@ -1622,7 +3495,67 @@ where
     where
         S: AsRef<str>,
     {
-        format!("{}{}", self.endpoint, path.as_ref())
+        format!("{}/{}", self.endpoint, path.as_ref())
+    }
+}
+
+/// How much of an unexpected (e.g. HTML) response body is included into [`Error::UnexpectedContentType`].
+const UNEXPECTED_BODY_PREFIX_LENGTH: usize = 256;
+
+/// Returns the content type of a response if it is set and is not JSON.
+fn non_json_content_type(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(CONTENT_TYPE)?;
+    let content_type = value.to_str().unwrap_or_default();
+    if content_type.contains("json") {
+        None
+    } else {
+        Some(content_type.to_owned())
+    }
+}
+
+/// Returns the path of a binding, e.g. `/api/bindings/{vhost}/e/{source}/q/{destination}/{props}`.
+fn binding_path(binding: &BindingInfo) -> String {
+    let path_appreviation = binding.destination_type.path_appreviation();
+    match &binding.properties_key {
+        Some(pk) => path!(
+            "bindings",
+            &binding.vhost,
+            "e",
+            &binding.source,
+            path_appreviation,
+            &binding.destination,
+            pk.as_str()
+        ),
+        None => path!(
+            "bindings",
+            &binding.vhost,
+            "e",
+            &binding.source,
+            path_appreviation,
+            &binding.destination
+        ),
+    }
+}
+
+/// The password and OAuth 2 token are redacted.
+impl<E, U, P> fmt::Debug for Client<E, U, P>
+where
+    E: fmt::Display,
+    U: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("endpoint", &self.endpoint.to_string())
+            .field("username", &self.username.to_string())
+            .field("password", &"[redacted]")
+            .field(
+                "oauth2_token",
+                &self.oauth2_token.as_ref().map(|_| "[redacted]"),
+            )
+            .field("retry_policy", &self.retry_policy)
+            .field("request_timeout", &self.request_timeout)
+            .field("user_agent", &self.user_agent)
+            .finish_non_exhaustive()
     }
 }
 
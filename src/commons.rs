@@ -11,9 +11,13 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use std::convert::Infallible;
 use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
@@ -196,8 +200,10 @@ impl fmt::Display for SupportedProtocol {
 
 /// Exchange types. Most variants are for exchange types included with modern RabbitMQ distributions.
 /// For custom types provided by 3rd party plugins, use the `Plugin(String)` variant.
+///
+/// Always serialized as a plain JSON string, e.g. `"topic"` or `"x-message-deduplication"`.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
-#[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
+#[serde(from = "String", into = "String")]
 pub enum ExchangeType {
     /// Fanout exchange
     Fanout,
@@ -208,31 +214,22 @@ pub enum ExchangeType {
     /// Headers exchange
     Headers,
     /// Consistent hashing (consistent hash) exchange
-    #[serde(rename = "x-consistent-hash")]
     ConsistentHashing,
     /// Modulus hash, ships with the 'rabbitmq-sharding' plugin
-    #[serde(rename = "x-modulus-hash")]
     ModulusHash,
     /// Random exchange
-    #[serde(rename = "x-random")]
     Random,
     /// Local random exchange
-    #[serde(rename = "x-local-random")]
     LocalRandom,
     /// JMS topic exchange
-    #[serde(rename = "x-jms-topic")]
     JmsTopic,
     /// Recent history exchange
-    #[serde(rename = "x-recent-history")]
     RecentHistory,
     /// x-delayed-message exchange
-    #[serde(rename = "x-delayed-message")]
     DelayedMessage,
     /// x-message-deduplication
-    #[serde(rename = "x-message-deduplication")]
     MessageDeduplication,
     /// Other types
-    #[serde(untagged)]
     Plugin(String),
 }
 
@@ -295,33 +292,50 @@ impl From<ExchangeType> for String {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
-#[serde(rename_all(serialize = "lowercase", deserialize = "PascalCase"))]
+/// Returns the name of the plugin that provides the given exchange type,
+/// or `None` for the types that ship with RabbitMQ core and for unknown ones.
+pub fn required_plugins_for_exchange_type(exchange_type: &ExchangeType) -> Option<&'static str> {
+    match exchange_type {
+        ExchangeType::ConsistentHashing => Some("rabbitmq_consistent_hash_exchange"),
+        ExchangeType::ModulusHash => Some("rabbitmq_sharding"),
+        ExchangeType::Random => Some("rabbitmq_random_exchange"),
+        ExchangeType::JmsTopic => Some("rabbitmq_jms_topic_exchange"),
+        ExchangeType::RecentHistory => Some("rabbitmq_recent_history_exchange"),
+        ExchangeType::DelayedMessage => Some("rabbitmq_delayed_message_exchange"),
+        ExchangeType::MessageDeduplication => Some("rabbitmq_message_deduplication"),
+        _ => None,
+    }
+}
+
+/// Queue types.
+///
+/// Types not known to this library (e.g. introduced by a newer RabbitMQ version or a plugin)
+/// are represented by [`QueueType::Unsupported`].
+///
+/// Always serialized as a plain JSON string, e.g. `"quorum"`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(from = "String", into = "String")]
 pub enum QueueType {
     Classic,
     Quorum,
     Stream,
+    Unsupported(String),
 }
 
 impl From<&str> for QueueType {
     fn from(value: &str) -> Self {
-        match value {
-            "classic" => QueueType::Classic,
-            "quorum" => QueueType::Quorum,
-            "stream" => QueueType::Stream,
-            _ => QueueType::Classic,
+        match value.to_ascii_lowercase().as_str() {
+            "classic" | "cq" => QueueType::Classic,
+            "quorum" | "qq" => QueueType::Quorum,
+            "stream" | "sq" => QueueType::Stream,
+            _ => QueueType::Unsupported(value.to_owned()),
         }
     }
 }
 
 impl From<String> for QueueType {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "classic" => QueueType::Classic,
-            "quorum" => QueueType::Quorum,
-            "stream" => QueueType::Stream,
-            _ => QueueType::Classic,
-        }
+        QueueType::from(value.as_str())
     }
 }
 
@@ -331,6 +345,118 @@ impl From<QueueType> for String {
             QueueType::Classic => "classic".to_owned(),
             QueueType::Quorum => "quorum".to_owned(),
             QueueType::Stream => "stream".to_owned(),
+            QueueType::Unsupported(queue_type) => queue_type,
+        }
+    }
+}
+
+/// Parses a queue type the way a user would type it: case-insensitively
+/// and with the common abbreviations (`cq`, `qq`, `sq`) accepted.
+///
+/// Never fails: unknown values are preserved as [`QueueType::Unsupported`].
+impl FromStr for QueueType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(QueueType::from(s))
+    }
+}
+
+impl fmt::Display for QueueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.clone()))
+    }
+}
+
+/// What a queue does when its [maximum length](https://rabbitmq.com/docs/maxlength/#overflow-behaviour)
+/// is reached (the `x-overflow` argument or the `overflow` policy key).
+///
+/// Always serialized as a plain JSON string, e.g. `"reject-publish"`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum OverflowBehavior {
+    /// Drop or dead-letter the oldest messages
+    DropHead,
+    /// Reject new publishes
+    RejectPublish,
+    /// Reject new publishes and dead-letter them
+    RejectPublishDlx,
+    /// Values not known to this library
+    Other(String),
+}
+
+const OVERFLOW_BEHAVIOR_DROP_HEAD: &str = "drop-head";
+const OVERFLOW_BEHAVIOR_REJECT_PUBLISH: &str = "reject-publish";
+const OVERFLOW_BEHAVIOR_REJECT_PUBLISH_DLX: &str = "reject-publish-dlx";
+
+impl AsRef<str> for OverflowBehavior {
+    fn as_ref(&self) -> &str {
+        match self {
+            OverflowBehavior::DropHead => OVERFLOW_BEHAVIOR_DROP_HEAD,
+            OverflowBehavior::RejectPublish => OVERFLOW_BEHAVIOR_REJECT_PUBLISH,
+            OverflowBehavior::RejectPublishDlx => OVERFLOW_BEHAVIOR_REJECT_PUBLISH_DLX,
+            OverflowBehavior::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<&str> for OverflowBehavior {
+    fn from(value: &str) -> Self {
+        match value {
+            OVERFLOW_BEHAVIOR_DROP_HEAD => OverflowBehavior::DropHead,
+            OVERFLOW_BEHAVIOR_REJECT_PUBLISH => OverflowBehavior::RejectPublish,
+            OVERFLOW_BEHAVIOR_REJECT_PUBLISH_DLX => OverflowBehavior::RejectPublishDlx,
+            other => OverflowBehavior::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OverflowBehavior {
+    fn from(value: String) -> Self {
+        OverflowBehavior::from(value.as_str())
+    }
+}
+
+impl From<OverflowBehavior> for String {
+    fn from(value: OverflowBehavior) -> String {
+        match value {
+            OverflowBehavior::Other(value) => value,
+            known => known.as_ref().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OverflowBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// Message [delivery mode](https://rabbitmq.com/docs/publishers/#message-properties) (the `delivery_mode` property).
+///
+/// Serialized as a number, `1` or `2`, just like in AMQP 0-9-1.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum DeliveryMode {
+    #[default]
+    Transient = 1,
+    Persistent = 2,
+}
+
+impl From<DeliveryMode> for u8 {
+    fn from(value: DeliveryMode) -> u8 {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for DeliveryMode {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(DeliveryMode::Transient),
+            2 => Ok(DeliveryMode::Persistent),
+            other => Err(format!("unsupported delivery mode: {}", other)),
         }
     }
 }
@@ -404,6 +530,13 @@ pub enum PolicyTarget {
     All,
 }
 
+impl PolicyTarget {
+    /// Returns `true` if policies with this target apply to (some) queues or streams.
+    pub fn includes_queues(&self) -> bool {
+        !matches!(self, PolicyTarget::Exchanges)
+    }
+}
+
 impl fmt::Display for PolicyTarget {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", Into::<String>::into(self.clone()))?;
@@ -453,6 +586,122 @@ impl From<PolicyTarget> for String {
     }
 }
 
+/// The scheme (protocol) used to connect to the HTTP API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    Http,
+    Https,
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scheme::Http => write!(f, "http"),
+            Scheme::Https => write!(f, "https"),
+        }
+    }
+}
+
+/// The [management plugin statistics mode](https://rabbitmq.com/docs/management#statistics-interval),
+/// which determines what rates (e.g. `messages_details.rate`) are collected and reported.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum RatesMode {
+    /// Only the most important rates are collected
+    Basic,
+    /// Rates are also collected for individual publisher/queue/exchange pairs
+    Detailed,
+    /// Rate collection is disabled
+    None,
+    /// Values not known to this library
+    Unknown(String),
+}
+
+impl fmt::Display for RatesMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.clone()))
+    }
+}
+
+impl From<&str> for RatesMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "basic" => RatesMode::Basic,
+            "detailed" => RatesMode::Detailed,
+            "none" => RatesMode::None,
+            other => RatesMode::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RatesMode {
+    fn from(value: String) -> Self {
+        RatesMode::from(value.as_str())
+    }
+}
+
+impl From<RatesMode> for String {
+    fn from(value: RatesMode) -> Self {
+        match value {
+            RatesMode::Basic => "basic".to_owned(),
+            RatesMode::Detailed => "detailed".to_owned(),
+            RatesMode::None => "none".to_owned(),
+            RatesMode::Unknown(mode) => mode,
+        }
+    }
+}
+
+/// Determines how a [federation link](https://rabbitmq.com/docs/federation-reference/#upstreams)
+/// acknowledges messages, trading off safety for throughput.
+///
+/// Modes not known to this library are represented by [`FederationAckMode::Unknown`].
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum FederationAckMode {
+    /// Messages are acknowledged to the upstream after they are confirmed downstream
+    OnConfirm,
+    /// Messages are acknowledged to the upstream after they are published downstream
+    OnPublish,
+    /// Messages are not acknowledged, the fastest and the least safe option
+    NoAck,
+    /// Values not known to this library
+    Unknown(String),
+}
+
+impl fmt::Display for FederationAckMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.clone()))
+    }
+}
+
+impl From<&str> for FederationAckMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "on-confirm" => FederationAckMode::OnConfirm,
+            "on-publish" => FederationAckMode::OnPublish,
+            "no-ack" => FederationAckMode::NoAck,
+            other => FederationAckMode::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FederationAckMode {
+    fn from(value: String) -> Self {
+        FederationAckMode::from(value.as_str())
+    }
+}
+
+impl From<FederationAckMode> for String {
+    fn from(value: FederationAckMode) -> Self {
+        match value {
+            FederationAckMode::OnConfirm => "on-confirm".to_owned(),
+            FederationAckMode::OnPublish => "on-publish".to_owned(),
+            FederationAckMode::NoAck => "no-ack".to_owned(),
+            FederationAckMode::Unknown(mode) => mode,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum VirtualHostLimitTarget {
@@ -536,3 +785,174 @@ impl From<UserLimitTarget> for String {
         value.as_ref().to_string()
     }
 }
+
+/// Time units used by the [certificate expiration health check](https://rabbitmq.com/docs/monitoring#health-checks).
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl AsRef<str> for TimeUnit {
+    fn as_ref(&self) -> &str {
+        match self {
+            TimeUnit::Days => "days",
+            TimeUnit::Weeks => "weeks",
+            TimeUnit::Months => "months",
+            TimeUnit::Years => "years",
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// A size in bytes, as used by queue, stream and policy arguments
+/// such as `x-max-length-bytes` or `max-length-bytes`.
+///
+/// Serializes to the raw integer number of bytes expected by RabbitMQ.
+/// Conversions from larger units saturate at `u64::MAX` instead of overflowing.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    pub const fn bytes(n: u64) -> Self {
+        ByteSize(n)
+    }
+
+    /// Kilobytes (1 kB = 1000 bytes)
+    pub const fn kb(n: u64) -> Self {
+        ByteSize(n.saturating_mul(1000))
+    }
+
+    /// Kibibytes (1 KiB = 1024 bytes)
+    pub const fn kib(n: u64) -> Self {
+        ByteSize(n.saturating_mul(1024))
+    }
+
+    /// Megabytes (1 MB = 1000 kB)
+    pub const fn mb(n: u64) -> Self {
+        ByteSize(n.saturating_mul(1000 * 1000))
+    }
+
+    /// Mebibytes (1 MiB = 1024 KiB)
+    pub const fn mib(n: u64) -> Self {
+        ByteSize(n.saturating_mul(1024 * 1024))
+    }
+
+    /// Gigabytes (1 GB = 1000 MB)
+    pub const fn gb(n: u64) -> Self {
+        ByteSize(n.saturating_mul(1000 * 1000 * 1000))
+    }
+
+    /// Gibibytes (1 GiB = 1024 MiB)
+    pub const fn gib(n: u64) -> Self {
+        ByteSize(n.saturating_mul(1024 * 1024 * 1024))
+    }
+
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(value: u64) -> Self {
+        ByteSize(value)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(value: ByteSize) -> Self {
+        value.0
+    }
+}
+
+impl From<ByteSize> for Value {
+    fn from(value: ByteSize) -> Self {
+        Value::from(value.0)
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes", self.0)
+    }
+}
+
+/// A duration in milliseconds, as used by queue and policy arguments
+/// such as `x-message-ttl`, `x-expires` or `message-ttl`.
+///
+/// Serializes to the raw integer number of milliseconds expected by RabbitMQ.
+/// Conversions from larger units saturate at `u64::MAX` instead of overflowing.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct MillisDuration(pub u64);
+
+impl MillisDuration {
+    pub const fn millis(n: u64) -> Self {
+        MillisDuration(n)
+    }
+
+    pub const fn seconds(n: u64) -> Self {
+        MillisDuration(n.saturating_mul(1000))
+    }
+
+    pub const fn minutes(n: u64) -> Self {
+        MillisDuration(n.saturating_mul(60 * 1000))
+    }
+
+    pub const fn hours(n: u64) -> Self {
+        MillisDuration(n.saturating_mul(60 * 60 * 1000))
+    }
+
+    pub const fn days(n: u64) -> Self {
+        MillisDuration(n.saturating_mul(24 * 60 * 60 * 1000))
+    }
+
+    pub const fn as_millis(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for MillisDuration {
+    fn from(value: u64) -> Self {
+        MillisDuration(value)
+    }
+}
+
+impl From<MillisDuration> for u64 {
+    fn from(value: MillisDuration) -> Self {
+        value.0
+    }
+}
+
+impl From<Duration> for MillisDuration {
+    fn from(value: Duration) -> Self {
+        MillisDuration(u64::try_from(value.as_millis()).unwrap_or(u64::MAX))
+    }
+}
+
+impl From<MillisDuration> for Duration {
+    fn from(value: MillisDuration) -> Self {
+        Duration::from_millis(value.0)
+    }
+}
+
+impl From<MillisDuration> for Value {
+    fn from(value: MillisDuration) -> Self {
+        Value::from(value.0)
+    }
+}
+
+impl fmt::Display for MillisDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ms", self.0)
+    }
+}
@@ -13,19 +13,23 @@
 // limitations under the License.
 #![allow(clippy::result_large_err)]
 
+use crate::commons::{required_plugins_for_exchange_type, ExchangeType};
+pub use crate::requests::ShovelValidationError;
 use crate::responses;
 use thiserror::Error;
 
 use backtrace::Backtrace;
 use reqwest::{
     header::{HeaderMap, InvalidHeaderValue},
-    StatusCode, Url,
+    Method, StatusCode, Url,
 };
 
 #[derive(Error, Debug)]
 pub enum Error<U, S, E, BT> {
     #[error("API responded with a client error: status code of {status_code}")]
     ClientErrorResponse {
+        /// The HTTP method of the request that failed
+        method: Option<Method>,
         url: Option<U>,
         status_code: S,
         body: Option<String>,
@@ -34,6 +38,8 @@ pub enum Error<U, S, E, BT> {
     },
     #[error("API responded with a server error: status code of {status_code}")]
     ServerErrorResponse {
+        /// The HTTP method of the request that failed
+        method: Option<Method>,
         url: Option<U>,
         status_code: S,
         body: Option<String>,
@@ -50,17 +56,111 @@ pub enum Error<U, S, E, BT> {
     NotFound,
     #[error("Cannot delete a binding: multiple matching bindings were found, provide additional properties")]
     MultipleMatchingBindings,
+    #[error("Virtual host {name} is protected from deletion")]
+    VirtualHostProtectedFromDeletion { name: String },
+    #[error("Limit {kind} was not applied: expected {expected}, the node reported {actual:?}")]
+    LimitNotApplied {
+        kind: String,
+        expected: i64,
+        actual: Option<i64>,
+    },
+    #[error("API responded with an unexpected content type {content_type}, is the rabbitmq_management plugin enabled and is the endpoint correct?")]
+    UnexpectedContentType {
+        content_type: String,
+        /// The beginning of the response body
+        body_prefix: String,
+    },
+    #[error("Aliveness check failed for virtual host {vhost}: {body}")]
+    AlivenessCheckFailed { vhost: String, body: String },
+    #[error("invalid definitions: {reason}")]
+    InvalidDefinitions { reason: String },
+    #[error("Exchange type {exchange_type} could not be used, is the {plugin} plugin enabled?")]
+    LikelyMissingPlugin {
+        exchange_type: String,
+        plugin: String,
+        /// The original error response
+        #[source]
+        source: Box<Error<U, S, E, BT>>,
+    },
+    #[error("Message was published but not routed to any queues or streams")]
+    MessageNotRouted,
+    #[error("invalid regular expression: {pattern}")]
+    InvalidRegex {
+        pattern: String,
+        error: fancy_regex::Error,
+    },
+    #[error("invalid endpoint {endpoint}: {reason}")]
+    InvalidEndpoint { endpoint: String, reason: String },
+    #[error("invalid TLS configuration: {reason}")]
+    InvalidTlsConfiguration { reason: String },
+    #[error("invalid proxy URL {url}: {reason}")]
+    InvalidProxy { url: String, reason: String },
+    #[error("invalid shovel definition: {error}")]
+    InvalidShovelDefinition { error: ShovelValidationError },
     #[error("could not convert provided value into an HTTP header value")]
     InvalidHeaderValue { error: InvalidHeaderValue },
     #[error("encountered an error when performing an HTTP request")]
     RequestError { error: E, backtrace: BT },
+    #[error("failed to write the response body")]
+    WriteError { error: std::io::Error },
+    #[error("{} of the operations failed", .failures.len())]
+    PartialFailure {
+        /// Names of the objects the operation failed for, and the errors
+        failures: Vec<(String, Error<U, S, E, BT>)>,
+    },
+    #[error("{} of the queue purges failed", .failures.len())]
+    PartialPurgeFailure {
+        /// The queues that were purged and the queues that did not exist
+        report: responses::PurgeReport,
+        /// Names of the queues that could not be purged, and the errors
+        failures: Vec<(String, Error<U, S, E, BT>)>,
+    },
     #[error("an unspecified error")]
     Other,
 }
 
+/// What the reasons of exchange declaration failures caused by an unknown exchange type contain
+const UNKNOWN_EXCHANGE_TYPE_MARKERS: [&str; 3] = [
+    "unknown exchange type",
+    "invalid exchange type",
+    "invalid type",
+];
+
 #[allow(unused)]
 pub type HttpClientError = Error<Url, StatusCode, reqwest::Error, Backtrace>;
 
+impl HttpClientError {
+    /// Nodes that do not have the plugin providing an exchange type enabled
+    /// reject declarations of such exchanges with a response that says the type is unknown.
+    /// Turns such errors into a more actionable [`Error::LikelyMissingPlugin`] that retains the original error.
+    ///
+    /// Other errors, including server errors that do not mention the type, are returned as is.
+    pub(crate) fn or_likely_missing_plugin(self, exchange_type: &ExchangeType) -> Self {
+        let Some(plugin) = required_plugins_for_exchange_type(exchange_type) else {
+            return self;
+        };
+
+        let body = match &self {
+            Error::ClientErrorResponse { body, .. } | Error::ServerErrorResponse { body, .. } => {
+                body.as_deref().unwrap_or_default()
+            }
+            _ => return self,
+        };
+        let indicates_unknown_type = UNKNOWN_EXCHANGE_TYPE_MARKERS
+            .iter()
+            .any(|marker| body.contains(marker));
+        if !indicates_unknown_type {
+            return self;
+        }
+
+        Error::LikelyMissingPlugin {
+            exchange_type: String::from(exchange_type.clone()),
+            plugin: plugin.to_owned(),
+            source: Box::new(self),
+        }
+    }
+}
+
 impl From<reqwest::Error> for HttpClientError {
     fn from(req_err: reqwest::Error) -> Self {
         match req_err.status() {
@@ -71,6 +171,7 @@ impl From<reqwest::Error> for HttpClientError {
             Some(status_code) => {
                 if status_code.is_client_error() {
                     return HttpClientError::ClientErrorResponse {
+                        method: None,
                         url: req_err.url().cloned(),
                         status_code,
                         body: None,
@@ -81,6 +182,7 @@ impl From<reqwest::Error> for HttpClientError {
 
                 if status_code.is_server_error() {
                     return HttpClientError::ServerErrorResponse {
+                        method: None,
                         url: req_err.url().cloned(),
                         status_code,
                         body: None,
@@ -103,3 +205,15 @@ impl From<reqwest::header::InvalidHeaderValue> for HttpClientError {
         HttpClientError::InvalidHeaderValue { error: err }
     }
 }
+
+impl From<std::io::Error> for HttpClientError {
+    fn from(err: std::io::Error) -> Self {
+        HttpClientError::WriteError { error: err }
+    }
+}
+
+impl From<ShovelValidationError> for HttpClientError {
+    fn from(err: ShovelValidationError) -> Self {
+        HttpClientError::InvalidShovelDefinition { error: err }
+    }
+}
@@ -51,5 +51,10 @@ pub mod responses;
 /// Error
 #[cfg(any(feature = "async", feature = "blocking"))]
 pub mod error;
+/// Retry policies for transient HTTP API request failures
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub mod retries;
+#[cfg(all(feature = "tls", any(feature = "async", feature = "blocking")))]
+mod tls;
 #[cfg(any(feature = "async", feature = "blocking"))]
 mod utils;
@@ -11,8 +11,11 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use md5::{Digest, Md5};
 use rand::distributions::{Alphanumeric, DistString};
-use ring::digest::{Context, SHA256};
+use ring::digest::{Context, SHA256, SHA512};
+use serde::Serialize;
+use std::fmt;
 
 const SALT_LENGTH: usize = 4;
 
@@ -26,21 +29,83 @@ pub fn salt() -> Vec<u8> {
     Vec::from(bytes)
 }
 
-/// Produces a SHA-256 hashed, salted passowrd hash.
-/// Prefer [`base64_encoded_salted_password_hash_sha256`].
+/// Password hashing algorithms supported by RabbitMQ.
 ///
+/// The algorithm used for a pre-hashed password must match the `password_hashing_module`
+/// configured on the target nodes (or be passed as [`crate::requests::UserParams::hashing_algorithm`]).
 /// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
-pub fn salted_password_hash_sha256(salt: &[u8], password: &str) -> Vec<u8> {
-    let mut ctx = Context::new(&SHA256);
-    let vec = [salt, password.as_bytes()].concat();
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(into = "String")]
+pub enum HashingAlgorithm {
+    /// SHA-256, the default
+    #[default]
+    Sha256,
+    /// SHA-512
+    Sha512,
+    /// MD5, only used by very old RabbitMQ versions
+    Md5,
+}
 
-    ctx.update(&vec);
-    let digest = ctx.finish();
-    let digest_vec = Vec::from(digest.as_ref());
+impl AsRef<str> for HashingAlgorithm {
+    /// Returns the name of the RabbitMQ module that implements this algorithm.
+    fn as_ref(&self) -> &str {
+        match self {
+            HashingAlgorithm::Sha256 => "rabbit_password_hashing_sha256",
+            HashingAlgorithm::Sha512 => "rabbit_password_hashing_sha512",
+            HashingAlgorithm::Md5 => "rabbit_password_hashing_md5",
+        }
+    }
+}
+
+impl From<HashingAlgorithm> for String {
+    fn from(value: HashingAlgorithm) -> Self {
+        value.as_ref().to_owned()
+    }
+}
+
+impl fmt::Display for HashingAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+/// Produces a salted password hash using the given algorithm.
+/// Prefer [`hash_password_with`].
+///
+/// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
+pub fn salted_password_hash_with(
+    algorithm: HashingAlgorithm,
+    salt: &[u8],
+    password: &str,
+) -> Vec<u8> {
+    let vec = [salt, password.as_bytes()].concat();
+    let digest_vec = match algorithm {
+        HashingAlgorithm::Sha256 => ring_digest(&SHA256, &vec),
+        HashingAlgorithm::Sha512 => ring_digest(&SHA512, &vec),
+        HashingAlgorithm::Md5 => Md5::digest(&vec).to_vec(),
+    };
 
     [salt, &digest_vec[..]].concat()
 }
 
+/// Produces a Base64-encoded, salted password hash using the given algorithm. The result can be passed
+/// as [`crate::requests::UserParams::password_hash`], together with the algorithm
+/// as [`crate::requests::UserParams::hashing_algorithm`].
+///
+/// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
+pub fn hash_password_with(algorithm: HashingAlgorithm, salt: &[u8], password: &str) -> String {
+    let salted = salted_password_hash_with(algorithm, salt, password);
+    rbase64::encode(salted.as_slice())
+}
+
+/// Produces a SHA-256 hashed, salted passowrd hash.
+/// Prefer [`base64_encoded_salted_password_hash_sha256`].
+///
+/// See the [Credentials and Passwords guide](https://rabbitmq.com/docs/passwords/).
+pub fn salted_password_hash_sha256(salt: &[u8], password: &str) -> Vec<u8> {
+    salted_password_hash_with(HashingAlgorithm::Sha256, salt, password)
+}
+
 ///
 /// Produces a Base64-encoded, SHA-256 hashed, salted passowrd hash that can be passed
 /// as [`crate::requests::UserParams::password_hash`] when adding a user with [`crate::blocking_api::Client::create_user`].
@@ -50,3 +115,10 @@ pub fn base64_encoded_salted_password_hash_sha256(salt: &[u8], password: &str) -
     let salted = salted_password_hash_sha256(salt, password);
     rbase64::encode(salted.as_slice())
 }
+
+fn ring_digest(algorithm: &'static ring::digest::Algorithm, data: &[u8]) -> Vec<u8> {
+    let mut ctx = Context::new(algorithm);
+    ctx.update(data);
+    let digest = ctx.finish();
+    Vec::from(digest.as_ref())
+}
@@ -11,9 +11,16 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use crate::commons::{ExchangeType, PolicyTarget, QueueType};
+use crate::commons::{
+    ByteSize, DeliveryMode, ExchangeType, FederationAckMode, MillisDuration, OverflowBehavior,
+    PolicyTarget, QueueType,
+};
+use crate::password_hashing::HashingAlgorithm;
+use crate::responses::QueueInfo;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
+use std::fmt;
+use std::time::Duration;
 
 /// Properties of a [virtual host](https://rabbitmq.com/docs/vhosts/) to be created or updated.
 #[derive(Serialize)]
@@ -23,10 +30,14 @@ pub struct VirtualHostParams<'a> {
     /// Optional description, e.g. what purpose does this virtual host serve?
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
-    // A list of virtual host tags
+    /// A list of virtual host tags
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<&'a str>>,
-    //
+    /// The queue type used when clients do not explicitly specify one.
+    ///
+    /// When not set, the cluster-wide default is used. That default is configured
+    /// using the `default_queue_type` key in `rabbitmq.conf` and cannot be changed
+    /// via the HTTP API.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_queue_type: Option<QueueType>,
     pub tracing: bool,
@@ -68,6 +79,10 @@ pub struct UserParams<'a> {
     pub password_hash: &'a str,
     /// A comma-separate list of user tags
     pub tags: &'a str,
+    /// The algorithm that was used to hash the password. Must be provided if it differs
+    /// from the `password_hashing_module` configured on the target nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashing_algorithm: Option<HashingAlgorithm>,
 }
 
 pub type XArguments = Option<Map<String, Value>>;
@@ -164,6 +179,265 @@ impl<'a> QueueParams<'a> {
 
         Some(result)
     }
+
+    /// Sets an [optional queue argument](https://rabbitmq.com/docs/queues/#optional-arguments).
+    pub fn with_argument<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.arguments
+            .get_or_insert_with(Map::new)
+            .insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Sets [per-queue message TTL](https://rabbitmq.com/docs/ttl/#per-queue-message-ttl) (`x-message-ttl`).
+    pub fn with_message_ttl(self, ttl: MillisDuration) -> Self {
+        self.with_argument("x-message-ttl", ttl)
+    }
+
+    /// Sets [queue TTL](https://rabbitmq.com/docs/ttl/#queue-ttl) (`x-expires`).
+    pub fn with_expires(self, ttl: MillisDuration) -> Self {
+        self.with_argument("x-expires", ttl)
+    }
+
+    /// Sets the [maximum queue or stream length in bytes](https://rabbitmq.com/docs/maxlength/) (`x-max-length-bytes`).
+    pub fn with_max_length_bytes(self, size: ByteSize) -> Self {
+        self.with_argument("x-max-length-bytes", size)
+    }
+
+    /// Sets the maximum [stream](https://rabbitmq.com/docs/streams/) segment file size (`x-stream-max-segment-size-bytes`).
+    pub fn with_stream_max_segment_size_bytes(self, size: ByteSize) -> Self {
+        self.with_argument("x-stream-max-segment-size-bytes", size)
+    }
+
+    /// Sets the maximum age of [stream](https://rabbitmq.com/docs/streams/#retention) segments (`x-max-age`),
+    /// e.g. `"7D"` or `"12h"`. Durations are rounded down to whole seconds and
+    /// expressed using the largest unit that represents them exactly.
+    pub fn with_stream_max_age(self, max_age: Duration) -> Self {
+        self.with_argument("x-max-age", stream_max_age(max_age))
+    }
+
+    /// Instantiates a [`QueueParamsBuilder`] for a durable classic queue with the given name.
+    pub fn builder(name: &'a str) -> QueueParamsBuilder<'a> {
+        QueueParamsBuilder::new(name)
+    }
+}
+
+/// Builds [`QueueParams`] using typed setters for the queue type and commonly used
+/// [optional queue arguments](https://rabbitmq.com/docs/queues/#optional-arguments),
+/// so that the `x-` argument keys are always spelled correctly.
+///
+/// Example
+/// ```rust
+/// use std::time::Duration;
+/// use rabbitmq_http_client::commons::{OverflowBehavior, QueueType};
+/// use rabbitmq_http_client::requests::QueueParams;
+///
+/// let params = QueueParams::builder("orders")
+///     .queue_type(QueueType::Quorum)
+///     .max_length(100_000)
+///     .message_ttl(Duration::from_secs(3600))
+///     .dead_letter_exchange("orders.dlx")
+///     .overflow(OverflowBehavior::RejectPublish)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueueParamsBuilder<'a> {
+    name: &'a str,
+    queue_type: QueueType,
+    durable: bool,
+    auto_delete: bool,
+    exclusive: bool,
+    arguments: Map<String, Value>,
+}
+
+impl<'a> QueueParamsBuilder<'a> {
+    /// Instantiates a builder for a durable classic queue with the given name.
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            queue_type: QueueType::Classic,
+            durable: true,
+            auto_delete: false,
+            exclusive: false,
+            arguments: Map::new(),
+        }
+    }
+
+    /// Sets the queue type (`x-queue-type`).
+    pub fn queue_type(mut self, queue_type: QueueType) -> Self {
+        self.queue_type = queue_type;
+        self
+    }
+
+    pub fn durable(mut self, durable: bool) -> Self {
+        self.durable = durable;
+        self
+    }
+
+    pub fn auto_delete(mut self, auto_delete: bool) -> Self {
+        self.auto_delete = auto_delete;
+        self
+    }
+
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Sets the [maximum queue length in messages](https://rabbitmq.com/docs/maxlength/) (`x-max-length`).
+    pub fn max_length(self, max_length: u64) -> Self {
+        self.argument("x-max-length", max_length)
+    }
+
+    /// Sets the [maximum queue length in bytes](https://rabbitmq.com/docs/maxlength/) (`x-max-length-bytes`).
+    pub fn max_length_bytes(self, size: ByteSize) -> Self {
+        self.argument("x-max-length-bytes", size)
+    }
+
+    /// Sets [per-queue message TTL](https://rabbitmq.com/docs/ttl/#per-queue-message-ttl) (`x-message-ttl`).
+    pub fn message_ttl(self, ttl: Duration) -> Self {
+        self.argument("x-message-ttl", MillisDuration::from(ttl))
+    }
+
+    /// Sets [queue TTL](https://rabbitmq.com/docs/ttl/#queue-ttl) (`x-expires`).
+    pub fn expires(self, ttl: Duration) -> Self {
+        self.argument("x-expires", MillisDuration::from(ttl))
+    }
+
+    /// Sets the [dead letter exchange](https://rabbitmq.com/docs/dlx/) (`x-dead-letter-exchange`).
+    pub fn dead_letter_exchange(self, exchange: &str) -> Self {
+        self.argument("x-dead-letter-exchange", exchange)
+    }
+
+    /// Sets the [dead letter routing key](https://rabbitmq.com/docs/dlx/) (`x-dead-letter-routing-key`).
+    pub fn dead_letter_routing_key(self, routing_key: &str) -> Self {
+        self.argument("x-dead-letter-routing-key", routing_key)
+    }
+
+    /// Sets the [overflow behavior](https://rabbitmq.com/docs/maxlength/#overflow-behaviour) (`x-overflow`).
+    pub fn overflow(self, behavior: OverflowBehavior) -> Self {
+        self.argument("x-overflow", behavior.as_ref())
+    }
+
+    /// Sets an arbitrary [optional queue argument](https://rabbitmq.com/docs/queues/#optional-arguments).
+    pub fn argument<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.arguments.insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Returns [`QueueParams`] with the configured properties and arguments.
+    pub fn build(self) -> QueueParams<'a> {
+        let mut params = QueueParams::new(
+            self.name,
+            self.queue_type,
+            self.durable,
+            self.auto_delete,
+            Some(self.arguments),
+        );
+        params.exclusive = self.exclusive;
+        params
+    }
+}
+
+/// Filters used to list a subset of queues and streams.
+///
+/// The virtual host and name (a regular expression) filters are applied server-side,
+/// the queue type filter is applied client-side, after the results are returned by the server.
+#[derive(Debug, Default, Clone)]
+pub struct QueueFilter {
+    /// Only list queues in this virtual host (server-side)
+    pub vhost: Option<String>,
+    /// Only list queues whose name matches this regular expression (server-side)
+    pub name_regex: Option<String>,
+    /// Only list queues of this type (client-side)
+    pub queue_type: Option<QueueType>,
+}
+
+impl QueueFilter {
+    /// Returns true if the given queue matches the client-side part of this filter.
+    pub fn matches(&self, queue: &QueueInfo) -> bool {
+        match &self.queue_type {
+            Some(qt) => qt.to_string() == queue.queue_type,
+            None => true,
+        }
+    }
+}
+
+/// A server-side name filter for paginated queue listing, see `Client#list_queues_paged`.
+#[derive(Debug, Clone, Copy)]
+pub struct QueuePageFilter<'a> {
+    /// A name substring or, if `use_regex` is true, a regular expression
+    pub name: &'a str,
+    pub use_regex: bool,
+}
+
+impl<'a> QueuePageFilter<'a> {
+    /// Instantiates a [`QueuePageFilter`] that matches queues whose name contains `name`.
+    pub fn name_containing(name: &'a str) -> Self {
+        Self {
+            name,
+            use_regex: false,
+        }
+    }
+
+    /// Instantiates a [`QueuePageFilter`] that matches queues whose name matches a regular expression.
+    pub fn name_matching(pattern: &'a str) -> Self {
+        Self {
+            name: pattern,
+            use_regex: true,
+        }
+    }
+}
+
+/// Optional detail sections to include in `Client#get_node_info_with_details` responses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeInfoFlags {
+    /// Include a memory usage breakdown
+    pub memory: bool,
+    /// Include a breakdown of binary heap usage
+    pub binary: bool,
+    /// Include a breakdown of memory used by ETS tables
+    pub ets: bool,
+}
+
+impl NodeInfoFlags {
+    /// Instantiates a [`NodeInfoFlags`] with all detail sections enabled.
+    pub fn all() -> Self {
+        Self {
+            memory: true,
+            binary: true,
+            ets: true,
+        }
+    }
+
+    pub fn with_memory(mut self) -> Self {
+        self.memory = true;
+        self
+    }
+
+    pub fn with_binary(mut self) -> Self {
+        self.binary = true;
+        self
+    }
+
+    pub fn with_ets(mut self) -> Self {
+        self.ets = true;
+        self
+    }
+
+    /// Returns the query parameters that correspond to the enabled detail sections.
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if self.memory {
+            params.push(("memory", "true".to_owned()));
+        }
+        if self.binary {
+            params.push(("binary", "true".to_owned()));
+        }
+        if self.ets {
+            params.push(("ets", "true".to_owned()));
+        }
+        params
+    }
 }
 
 /// Exchange properties used at queue declaration time
@@ -338,6 +612,320 @@ pub struct RuntimeParameterDefinition {
     pub value: RuntimeParameterValue,
 }
 
+/// The runtime parameter component used by [federation upstreams](https://rabbitmq.com/docs/federation-reference/#upstreams).
+pub const FEDERATION_UPSTREAM_COMPONENT: &str = "federation-upstream";
+
+/// Represents a [federation upstream](https://rabbitmq.com/docs/federation-reference/#upstreams),
+/// which is stored as a runtime parameter of the `federation-upstream` component.
+///
+/// Use [`FederationUpstreamParams::builder`] to construct one and convert it
+/// into a [`RuntimeParameterDefinition`] to declare it with `Client#upsert_runtime_parameter`.
+#[derive(Debug, Clone)]
+pub struct FederationUpstreamParams<'a> {
+    pub vhost: &'a str,
+    pub name: &'a str,
+    pub value: RuntimeParameterValue,
+}
+
+impl<'a> FederationUpstreamParams<'a> {
+    /// Instantiates a [`FederationUpstreamParamsBuilder`] for an upstream in the given virtual host.
+    pub fn builder(vhost: &'a str, name: &'a str) -> FederationUpstreamParamsBuilder<'a> {
+        FederationUpstreamParamsBuilder::new(vhost, name)
+    }
+}
+
+impl From<FederationUpstreamParams<'_>> for RuntimeParameterDefinition {
+    fn from(params: FederationUpstreamParams<'_>) -> Self {
+        RuntimeParameterDefinition {
+            name: params.name.to_owned(),
+            vhost: params.vhost.to_owned(),
+            component: FEDERATION_UPSTREAM_COMPONENT.to_owned(),
+            value: params.value,
+        }
+    }
+}
+
+/// Builds [`FederationUpstreamParams`] using typed setters, so that
+/// the upstream definition keys are always spelled correctly.
+/// Only the keys that were set are included into the definition.
+///
+/// Example
+/// ```rust
+/// use std::time::Duration;
+/// use rabbitmq_http_client::commons::FederationAckMode;
+/// use rabbitmq_http_client::requests::{FederationUpstreamParams, RuntimeParameterDefinition};
+///
+/// let params = FederationUpstreamParams::builder("/", "upstream.1")
+///     .uri("amqp://upstream.local:5672")
+///     .ack_mode(FederationAckMode::OnConfirm)
+///     .prefetch_count(500)
+///     .reconnect_delay(Duration::from_secs(5))
+///     .build();
+/// let definition = RuntimeParameterDefinition::from(params);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FederationUpstreamParamsBuilder<'a> {
+    vhost: &'a str,
+    name: &'a str,
+    value: RuntimeParameterValue,
+}
+
+impl<'a> FederationUpstreamParamsBuilder<'a> {
+    pub fn new(vhost: &'a str, name: &'a str) -> Self {
+        Self {
+            vhost,
+            name,
+            value: Map::new(),
+        }
+    }
+
+    /// Sets the upstream [URI](https://rabbitmq.com/docs/uri-spec/) (`uri`).
+    pub fn uri(self, uri: &str) -> Self {
+        self.key("uri", uri)
+    }
+
+    /// Sets the maximum number of unacknowledged messages a federation link can have (`prefetch-count`).
+    pub fn prefetch_count(self, prefetch_count: u32) -> Self {
+        self.key("prefetch-count", prefetch_count)
+    }
+
+    /// Sets the acknowledgement mode used by federation links (`ack-mode`).
+    pub fn ack_mode(self, ack_mode: FederationAckMode) -> Self {
+        self.key("ack-mode", String::from(ack_mode))
+    }
+
+    /// Sets how long a federation link waits before reconnecting (`reconnect-delay`).
+    /// The delay is rounded down to whole seconds.
+    pub fn reconnect_delay(self, delay: Duration) -> Self {
+        self.key("reconnect-delay", delay.as_secs())
+    }
+
+    /// Sets whether federation links should preserve the `user-id` message property (`trust-user-id`).
+    pub fn trust_user_id(self, trust_user_id: bool) -> Self {
+        self.key("trust-user-id", trust_user_id)
+    }
+
+    /// Sets the maximum number of federation links a message can traverse (`max-hops`).
+    pub fn max_hops(self, max_hops: u32) -> Self {
+        self.key("max-hops", max_hops)
+    }
+
+    /// Sets the TTL of the upstream queue used by exchange federation (`expires`).
+    pub fn expires(self, ttl: Duration) -> Self {
+        self.key("expires", MillisDuration::from(ttl))
+    }
+
+    /// Sets the message TTL of the upstream queue used by exchange federation (`message-ttl`).
+    pub fn message_ttl(self, ttl: Duration) -> Self {
+        self.key("message-ttl", MillisDuration::from(ttl))
+    }
+
+    fn key<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.value.insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Returns [`FederationUpstreamParams`] with the configured keys.
+    pub fn build(self) -> FederationUpstreamParams<'a> {
+        FederationUpstreamParams {
+            vhost: self.vhost,
+            name: self.name,
+            value: self.value,
+        }
+    }
+}
+
+/// The runtime parameter component used by [dynamic shovels](https://rabbitmq.com/docs/shovel-dynamic).
+pub const SHOVEL_COMPONENT: &str = "shovel";
+
+fn is_blank(value: Option<&str>) -> bool {
+    match value {
+        Some(s) => s.is_empty(),
+        None => true,
+    }
+}
+
+/// Reasons a [dynamic shovel](https://rabbitmq.com/docs/shovel-dynamic) definition
+/// can be rejected before it is sent to the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShovelValidationError {
+    MissingSourceUri,
+    MissingDestinationUri,
+    MissingSource,
+    AmbiguousSource,
+    AmbiguousDestination,
+    MissingSourceAddress,
+    MissingDestinationAddress,
+}
+
+impl fmt::Display for ShovelValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ShovelValidationError::MissingSourceUri => "source URI is missing",
+            ShovelValidationError::MissingDestinationUri => "destination URI is missing",
+            ShovelValidationError::MissingSource => {
+                "either a source queue or a source exchange must be specified"
+            }
+            ShovelValidationError::AmbiguousSource => {
+                "a source queue and a source exchange cannot both be specified"
+            }
+            ShovelValidationError::AmbiguousDestination => {
+                "a destination queue and a destination exchange cannot both be specified"
+            }
+            ShovelValidationError::MissingSourceAddress => "source address is missing",
+            ShovelValidationError::MissingDestinationAddress => "destination address is missing",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for ShovelValidationError {}
+
+/// Represents a [dynamic shovel](https://rabbitmq.com/docs/shovel-dynamic) that uses
+/// AMQP 0-9-1 for both its source and destination.
+///
+/// Exactly one of `source_queue` and `source_exchange` must be set.
+/// At most one of `destination_queue` and `destination_exchange` can be set.
+#[derive(Debug, Clone, Default)]
+pub struct Amqp091ShovelParams<'a> {
+    pub vhost: &'a str,
+    pub name: &'a str,
+    /// Shovels use the same acknowledgement modes as federation links
+    pub acknowledgement_mode: Option<FederationAckMode>,
+    /// Reconnection delay in seconds
+    pub reconnect_delay: Option<u32>,
+    pub source_uri: &'a str,
+    pub source_queue: Option<&'a str>,
+    pub source_exchange: Option<&'a str>,
+    pub source_exchange_routing_key: Option<&'a str>,
+    pub destination_uri: &'a str,
+    pub destination_queue: Option<&'a str>,
+    pub destination_exchange: Option<&'a str>,
+    pub destination_exchange_routing_key: Option<&'a str>,
+}
+
+impl Amqp091ShovelParams<'_> {
+    /// Checks the required keys, so that a misconfigured shovel is rejected
+    /// before a request is sent instead of failing with a generic 400 response.
+    pub fn validate(&self) -> Result<(), ShovelValidationError> {
+        if self.source_uri.is_empty() {
+            return Err(ShovelValidationError::MissingSourceUri);
+        }
+        if self.destination_uri.is_empty() {
+            return Err(ShovelValidationError::MissingDestinationUri);
+        }
+        match (is_blank(self.source_queue), is_blank(self.source_exchange)) {
+            (true, true) => return Err(ShovelValidationError::MissingSource),
+            (false, false) => return Err(ShovelValidationError::AmbiguousSource),
+            _ => (),
+        }
+        if !is_blank(self.destination_queue) && !is_blank(self.destination_exchange) {
+            return Err(ShovelValidationError::AmbiguousDestination);
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Amqp091ShovelParams<'_>> for RuntimeParameterDefinition {
+    fn from(params: Amqp091ShovelParams<'_>) -> Self {
+        let mut value = Map::new();
+        value.insert("src-protocol".to_owned(), json!("amqp091"));
+        value.insert("src-uri".to_owned(), json!(params.source_uri));
+        value.insert("dest-protocol".to_owned(), json!("amqp091"));
+        value.insert("dest-uri".to_owned(), json!(params.destination_uri));
+
+        let optional = [
+            ("src-queue", params.source_queue),
+            ("src-exchange", params.source_exchange),
+            ("src-exchange-key", params.source_exchange_routing_key),
+            ("dest-queue", params.destination_queue),
+            ("dest-exchange", params.destination_exchange),
+            ("dest-exchange-key", params.destination_exchange_routing_key),
+        ];
+        for (key, val) in optional {
+            if let Some(val) = val {
+                value.insert(key.to_owned(), json!(val));
+            }
+        }
+        if let Some(mode) = params.acknowledgement_mode {
+            value.insert("ack-mode".to_owned(), json!(String::from(mode)));
+        }
+        if let Some(delay) = params.reconnect_delay {
+            value.insert("reconnect-delay".to_owned(), json!(delay));
+        }
+
+        RuntimeParameterDefinition {
+            name: params.name.to_owned(),
+            vhost: params.vhost.to_owned(),
+            component: SHOVEL_COMPONENT.to_owned(),
+            value,
+        }
+    }
+}
+
+/// Represents a [dynamic shovel](https://rabbitmq.com/docs/shovel-dynamic) that uses
+/// AMQP 1.0 for both its source and destination.
+#[derive(Debug, Clone, Default)]
+pub struct Amqp10ShovelParams<'a> {
+    pub vhost: &'a str,
+    pub name: &'a str,
+    /// Shovels use the same acknowledgement modes as federation links
+    pub acknowledgement_mode: Option<FederationAckMode>,
+    /// Reconnection delay in seconds
+    pub reconnect_delay: Option<u32>,
+    pub source_uri: &'a str,
+    pub source_address: &'a str,
+    pub destination_uri: &'a str,
+    pub destination_address: &'a str,
+}
+
+impl Amqp10ShovelParams<'_> {
+    /// Checks the required keys, so that a misconfigured shovel is rejected
+    /// before a request is sent instead of failing with a generic 400 response.
+    pub fn validate(&self) -> Result<(), ShovelValidationError> {
+        if self.source_uri.is_empty() {
+            return Err(ShovelValidationError::MissingSourceUri);
+        }
+        if self.destination_uri.is_empty() {
+            return Err(ShovelValidationError::MissingDestinationUri);
+        }
+        if self.source_address.is_empty() {
+            return Err(ShovelValidationError::MissingSourceAddress);
+        }
+        if self.destination_address.is_empty() {
+            return Err(ShovelValidationError::MissingDestinationAddress);
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Amqp10ShovelParams<'_>> for RuntimeParameterDefinition {
+    fn from(params: Amqp10ShovelParams<'_>) -> Self {
+        let mut value = Map::new();
+        value.insert("src-protocol".to_owned(), json!("amqp10"));
+        value.insert("src-uri".to_owned(), json!(params.source_uri));
+        value.insert("src-address".to_owned(), json!(params.source_address));
+        value.insert("dest-protocol".to_owned(), json!("amqp10"));
+        value.insert("dest-uri".to_owned(), json!(params.destination_uri));
+        value.insert("dest-address".to_owned(), json!(params.destination_address));
+        if let Some(mode) = params.acknowledgement_mode {
+            value.insert("ack-mode".to_owned(), json!(String::from(mode)));
+        }
+        if let Some(delay) = params.reconnect_delay {
+            value.insert("reconnect-delay".to_owned(), json!(delay));
+        }
+
+        RuntimeParameterDefinition {
+            name: params.name.to_owned(),
+            vhost: params.vhost.to_owned(),
+            component: SHOVEL_COMPONENT.to_owned(),
+            value,
+        }
+    }
+}
+
 pub type PolicyDefinition = Option<Map<String, Value>>;
 
 /// Represents a [policy](https://rabbitmq.com/docs/parameters/#policies).
@@ -352,6 +940,163 @@ pub struct PolicyParams<'a> {
     pub definition: PolicyDefinition,
 }
 
+impl PolicyParams<'_> {
+    /// Sets a policy definition key.
+    pub fn with_definition_key<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.definition
+            .get_or_insert_with(Map::new)
+            .insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Sets [per-queue message TTL](https://rabbitmq.com/docs/ttl/#per-queue-message-ttl) (`message-ttl`).
+    pub fn with_message_ttl(self, ttl: MillisDuration) -> Self {
+        self.with_definition_key("message-ttl", ttl)
+    }
+
+    /// Sets [queue TTL](https://rabbitmq.com/docs/ttl/#queue-ttl) (`expires`).
+    pub fn with_expires(self, ttl: MillisDuration) -> Self {
+        self.with_definition_key("expires", ttl)
+    }
+
+    /// Sets the [maximum queue or stream length in bytes](https://rabbitmq.com/docs/maxlength/) (`max-length-bytes`).
+    pub fn with_max_length_bytes(self, size: ByteSize) -> Self {
+        self.with_definition_key("max-length-bytes", size)
+    }
+}
+
+impl<'a> PolicyParams<'a> {
+    /// Instantiates a [`PolicyParamsBuilder`] for a policy in the given virtual host.
+    pub fn builder(vhost: &'a str, name: &'a str) -> PolicyParamsBuilder<'a> {
+        PolicyParamsBuilder::new(vhost, name)
+    }
+}
+
+/// Builds [`PolicyParams`] using typed setters for the target and commonly used
+/// policy definition keys, so that the keys are always spelled correctly.
+///
+/// Unless set, the pattern is `.*`, the policy applies to queues and its priority is `0`.
+///
+/// Example
+/// ```rust
+/// use std::time::Duration;
+/// use rabbitmq_http_client::commons::PolicyTarget;
+/// use rabbitmq_http_client::requests::PolicyParams;
+///
+/// let params = PolicyParams::builder("/", "orders")
+///     .pattern("^orders\\.")
+///     .apply_to(PolicyTarget::QuorumQueues)
+///     .priority(10)
+///     .max_length(100_000)
+///     .dead_letter_exchange("orders.dlx")
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolicyParamsBuilder<'a> {
+    vhost: &'a str,
+    name: &'a str,
+    pattern: &'a str,
+    apply_to: PolicyTarget,
+    priority: i32,
+    definition: Map<String, Value>,
+}
+
+impl<'a> PolicyParamsBuilder<'a> {
+    pub fn new(vhost: &'a str, name: &'a str) -> Self {
+        Self {
+            vhost,
+            name,
+            pattern: ".*",
+            apply_to: PolicyTarget::Queues,
+            priority: 0,
+            definition: Map::new(),
+        }
+    }
+
+    /// Sets the regular expression that matches the names of queues, streams or exchanges.
+    pub fn pattern(mut self, pattern: &'a str) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Sets the kind of objects the policy applies to (`apply-to`).
+    pub fn apply_to(mut self, target: PolicyTarget) -> Self {
+        self.apply_to = target;
+        self
+    }
+
+    /// Sets the policy priority. When multiple policies match, the one with the greatest priority wins.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the [classic queue mirroring](https://www.rabbitmq.com/docs/3.13/ha) mode (`ha-mode`):
+    /// `all`, `exactly` or `nodes`.
+    pub fn ha_mode(self, mode: &str) -> Self {
+        self.definition_key("ha-mode", mode)
+    }
+
+    /// Sets the [maximum queue length in messages](https://rabbitmq.com/docs/maxlength/) (`max-length`).
+    pub fn max_length(self, max_length: u64) -> Self {
+        self.definition_key("max-length", max_length)
+    }
+
+    /// Sets the [maximum queue or stream length in bytes](https://rabbitmq.com/docs/maxlength/) (`max-length-bytes`).
+    pub fn max_length_bytes(self, size: ByteSize) -> Self {
+        self.definition_key("max-length-bytes", size)
+    }
+
+    /// Sets [per-queue message TTL](https://rabbitmq.com/docs/ttl/#per-queue-message-ttl) (`message-ttl`).
+    pub fn message_ttl(self, ttl: Duration) -> Self {
+        self.definition_key("message-ttl", MillisDuration::from(ttl))
+    }
+
+    /// Sets [queue TTL](https://rabbitmq.com/docs/ttl/#queue-ttl) (`expires`).
+    pub fn expires(self, ttl: Duration) -> Self {
+        self.definition_key("expires", MillisDuration::from(ttl))
+    }
+
+    /// Sets the [federation upstream set](https://rabbitmq.com/docs/federation-reference/#upstream-sets)
+    /// to federate matching objects with (`federation-upstream-set`).
+    pub fn federation_upstream_set(self, upstream_set: &str) -> Self {
+        self.definition_key("federation-upstream-set", upstream_set)
+    }
+
+    /// Sets the [dead letter exchange](https://rabbitmq.com/docs/dlx/) (`dead-letter-exchange`).
+    pub fn dead_letter_exchange(self, exchange: &str) -> Self {
+        self.definition_key("dead-letter-exchange", exchange)
+    }
+
+    /// Sets the [dead letter routing key](https://rabbitmq.com/docs/dlx/) (`dead-letter-routing-key`).
+    pub fn dead_letter_routing_key(self, routing_key: &str) -> Self {
+        self.definition_key("dead-letter-routing-key", routing_key)
+    }
+
+    /// Sets the [overflow behavior](https://rabbitmq.com/docs/maxlength/#overflow-behaviour) (`overflow`).
+    pub fn overflow(self, behavior: OverflowBehavior) -> Self {
+        self.definition_key("overflow", behavior.as_ref())
+    }
+
+    /// Sets an arbitrary policy definition key.
+    pub fn definition_key<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.definition.insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Returns [`PolicyParams`] with the configured properties and definition.
+    pub fn build(self) -> PolicyParams<'a> {
+        PolicyParams {
+            vhost: self.vhost,
+            name: self.name,
+            pattern: self.pattern,
+            apply_to: self.apply_to,
+            priority: self.priority,
+            definition: Some(self.definition),
+        }
+    }
+}
+
 /// Represents a user's [permission in a particular virtual host](https://rabbitmq.com/docs/access-control/).
 #[derive(Serialize)]
 pub struct Permissions<'a> {
@@ -362,4 +1107,103 @@ pub struct Permissions<'a> {
     pub write: &'a str,
 }
 
+/// Properties of a message published with `Client#publish_message` and similar functions,
+/// e.g. `content_type`, `delivery_mode` or `headers`. Use [`MessagePropertiesBuilder`]
+/// to construct them.
 pub type MessageProperties = Map<String, Value>;
+
+/// A builder for [`MessageProperties`].
+///
+/// Example
+/// ```rust
+/// use std::time::Duration;
+/// use rabbitmq_http_client::commons::DeliveryMode;
+/// use rabbitmq_http_client::requests::MessagePropertiesBuilder;
+///
+/// let props = MessagePropertiesBuilder::new()
+///     .content_type("application/json")
+///     .delivery_mode(DeliveryMode::Persistent)
+///     .expiration(Duration::from_secs(60))
+///     .header("x-tenant", "tenant-a")
+///     .build();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MessagePropertiesBuilder {
+    properties: Map<String, Value>,
+    headers: Map<String, Value>,
+}
+
+impl MessagePropertiesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the MIME content type (`content_type`), e.g. `application/json`.
+    pub fn content_type(self, content_type: &str) -> Self {
+        self.property("content_type", content_type)
+    }
+
+    /// Sets the content encoding (`content_encoding`), e.g. `gzip`.
+    pub fn content_encoding(self, content_encoding: &str) -> Self {
+        self.property("content_encoding", content_encoding)
+    }
+
+    /// Sets the delivery mode (`delivery_mode`): transient or persistent.
+    pub fn delivery_mode(self, mode: DeliveryMode) -> Self {
+        self.property("delivery_mode", u8::from(mode))
+    }
+
+    /// Sets the [message priority](https://rabbitmq.com/docs/priority/) (`priority`).
+    pub fn priority(self, priority: u8) -> Self {
+        self.property("priority", priority)
+    }
+
+    pub fn correlation_id(self, correlation_id: &str) -> Self {
+        self.property("correlation_id", correlation_id)
+    }
+
+    pub fn reply_to(self, reply_to: &str) -> Self {
+        self.property("reply_to", reply_to)
+    }
+
+    /// Sets [per-message TTL](https://rabbitmq.com/docs/ttl/#per-message-ttl-in-publishers) (`expiration`),
+    /// which is transferred as a string with the number of milliseconds.
+    pub fn expiration(self, ttl: Duration) -> Self {
+        self.property("expiration", ttl.as_millis().to_string())
+    }
+
+    pub fn message_id(self, message_id: &str) -> Self {
+        self.property("message_id", message_id)
+    }
+
+    /// Adds a message header (an entry in `headers`).
+    pub fn header<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.headers.insert(key.to_owned(), value.into());
+        self
+    }
+
+    fn property<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.properties.insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Returns [`MessageProperties`] with the configured properties and headers.
+    pub fn build(self) -> MessageProperties {
+        let mut properties = self.properties;
+        if !self.headers.is_empty() {
+            properties.insert("headers".to_owned(), Value::Object(self.headers));
+        }
+        properties
+    }
+}
+
+fn stream_max_age(max_age: Duration) -> String {
+    let secs = max_age.as_secs();
+    match secs {
+        0 => "0s".to_owned(),
+        n if n % 86_400 == 0 => format!("{}D", n / 86_400),
+        n if n % 3_600 == 0 => format!("{}h", n / 3_600),
+        n if n % 60 == 0 => format!("{}m", n / 60),
+        n => format!("{}s", n),
+    }
+}
@@ -11,9 +11,16 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use std::{fmt, ops};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt, ops,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use crate::commons::{BindingDestinationType, PolicyTarget};
+use crate::commons::{
+    BindingDestinationType, FederationAckMode, OverflowBehavior, PolicyTarget, QueueType,
+    RatesMode, SupportedProtocol, UserLimitTarget, VirtualHostLimitTarget,
+};
 use crate::formatting::*;
 use crate::utils::{percentage, percentage_as_text};
 use serde::{
@@ -31,12 +38,78 @@ use tabled::Tabled;
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TagList(pub Vec<String>);
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl TagList {
+    /// Returns true if the list includes the given tag.
+    pub fn contains(&self, tag: &str) -> bool {
+        self.0.iter().any(|t| t == tag)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct PluginList(pub Vec<String>);
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct XArguments(pub Map<String, serde_json::Value>);
 
+/// Typed accessors for the most commonly used [optional queue arguments](https://rabbitmq.com/docs/queues/#optional-arguments).
+///
+/// Numeric arguments can be set as strings by some clients, e.g. `"1000"`,
+/// so both numbers and numeric strings are accepted. Other values are ignored.
+impl XArguments {
+    /// Returns the queue type (`x-queue-type`).
+    pub fn queue_type(&self) -> Option<QueueType> {
+        self.get_str("x-queue-type").map(QueueType::from)
+    }
+
+    /// Returns the [maximum queue length in messages](https://rabbitmq.com/docs/maxlength/) (`x-max-length`).
+    pub fn max_length(&self) -> Option<i64> {
+        self.get_i64("x-max-length")
+    }
+
+    /// Returns the [maximum queue length in bytes](https://rabbitmq.com/docs/maxlength/) (`x-max-length-bytes`).
+    pub fn max_length_bytes(&self) -> Option<i64> {
+        self.get_i64("x-max-length-bytes")
+    }
+
+    /// Returns [per-queue message TTL](https://rabbitmq.com/docs/ttl/#per-queue-message-ttl)
+    /// in milliseconds (`x-message-ttl`).
+    pub fn message_ttl(&self) -> Option<i64> {
+        self.get_i64("x-message-ttl")
+    }
+
+    /// Returns [queue TTL](https://rabbitmq.com/docs/ttl/#queue-ttl) in milliseconds (`x-expires`).
+    pub fn expires(&self) -> Option<i64> {
+        self.get_i64("x-expires")
+    }
+
+    /// Returns the [dead letter exchange](https://rabbitmq.com/docs/dlx/) (`x-dead-letter-exchange`).
+    pub fn dead_letter_exchange(&self) -> Option<&str> {
+        self.get_str("x-dead-letter-exchange")
+    }
+
+    /// Returns the [dead letter routing key](https://rabbitmq.com/docs/dlx/) (`x-dead-letter-routing-key`).
+    pub fn dead_letter_routing_key(&self) -> Option<&str> {
+        self.get_str("x-dead-letter-routing-key")
+    }
+
+    /// Returns the [overflow behavior](https://rabbitmq.com/docs/maxlength/#overflow-behaviour) (`x-overflow`).
+    pub fn overflow(&self) -> Option<OverflowBehavior> {
+        self.get_str("x-overflow").map(OverflowBehavior::from)
+    }
+
+    fn get_str(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.as_str())
+    }
+
+    fn get_i64(&self, key: &str) -> Option<i64> {
+        match self.0.get(key)? {
+            serde_json::Value::Number(n) => n.as_i64(),
+            serde_json::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
@@ -85,8 +158,32 @@ impl fmt::Display for RuntimeParameterValue {
     }
 }
 
+/// A page of results returned by a paginated listing endpoint.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: usize,
+    pub page_count: usize,
+    #[serde(default)]
+    pub page_size: usize,
+    /// The number of items on this page
+    pub item_count: usize,
+    /// The number of items that matched the filter (if any) across all pages
+    #[serde(default)]
+    pub filtered_count: usize,
+    /// The total number of items, before any filters are applied
+    pub total_count: usize,
+}
+
+impl<T> Page<T> {
+    /// Returns true if this is the last page of results.
+    pub fn is_last(&self) -> bool {
+        self.page >= self.page_count
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
-pub struct NodeList(Vec<String>);
+pub struct NodeList(pub Vec<String>);
 
 impl fmt::Display for NodeList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -363,6 +460,68 @@ impl fmt::Display for NodeMemoryBreakdown {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct NodeEtsMemoryFootprint {
+    #[serde(rename = "ets_tables_memory")]
+    pub breakdown: NodeEtsMemoryBreakdown,
+}
+
+/// Memory used by individual ETS tables on a node, in bytes.
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct NodeEtsMemoryBreakdown {
+    /// Memory used by all ETS tables
+    pub total: u64,
+    /// Memory used by individual ETS tables, keyed by table name
+    #[serde(flatten)]
+    pub tables: BTreeMap<String, u64>,
+}
+
+#[allow(dead_code)]
+impl NodeEtsMemoryBreakdown {
+    pub fn grand_total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the table names and their footprints, largest tables first.
+    pub fn sorted_by_footprint(&self) -> Vec<(&str, u64)> {
+        let mut vec: Vec<(&str, u64)> = self
+            .tables
+            .iter()
+            .map(|(name, bytes)| (name.as_str(), *bytes))
+            .collect();
+        vec.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        vec
+    }
+
+    /// Returns the share of ETS memory used by a table, or `None` if there is no such table.
+    pub fn table_percentage(&self, table: &str) -> Option<f64> {
+        self.tables
+            .get(table)
+            .map(|bytes| percentage(*bytes, self.grand_total()))
+    }
+
+    /// Same as [`NodeEtsMemoryBreakdown::table_percentage`] but formatted as text, e.g. `"12.34%"`.
+    pub fn table_percentage_as_text(&self, table: &str) -> Option<String> {
+        self.tables
+            .get(table)
+            .map(|bytes| percentage_as_text(*bytes, self.grand_total()))
+    }
+}
+
+impl fmt::Display for NodeEtsMemoryBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (k, v) in self.sorted_by_footprint() {
+            writeln!(f, "{}: {}", k, v)?;
+        }
+        writeln!(f, "total: {}", self.total)?;
+
+        Ok(())
+    }
+}
+
 /// Represents a number of key OAuth 2 configuration settings.
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
@@ -403,11 +562,102 @@ pub struct VirtualHost {
     /// do not explicitly specify one
     #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
     pub default_queue_type: Option<String>,
+    /// Is this virtual host [protected from deletion](https://rabbitmq.com/docs/vhosts/#deletion-protection)?
+    /// Only reported by RabbitMQ 4.1 and later versions.
+    #[serde(default)]
+    pub protected_from_deletion: bool,
     /// All virtual host metadata combined
     #[cfg_attr(feature = "tabled", tabled(skip))]
     pub metadata: VirtualHostMetadata,
 }
 
+/// Summarizes the contents of a virtual host that was deleted
+/// with `Client#delete_vhost_with_report`.
+///
+/// The counts are a best-effort snapshot taken just before deletion:
+/// connections may be opened and queues declared after they were counted.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct VirtualHostDeletionReport {
+    pub vhost: String,
+    /// Was the virtual host deleted? `false` if it did not exist
+    /// and the deletion was performed idempotently.
+    pub deleted: bool,
+    pub connection_count: usize,
+    pub queue_count: usize,
+    /// The number of exchanges, excluding the default exchange and the `amq.*` ones
+    pub exchange_count: usize,
+    /// The total number of messages in all queues and streams in the virtual host
+    pub message_count: u64,
+}
+
+/// Summarizes the contents of a virtual host, see `Client#vhost_contents_summary`.
+///
+/// The counts are a best-effort snapshot: connections may be opened and
+/// queues declared after they were counted.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct VirtualHostContentsSummary {
+    pub vhost: String,
+    pub queue_count: usize,
+    /// The number of exchanges, excluding the default exchange and the `amq.*` ones
+    pub exchange_count: usize,
+    /// The number of bindings, excluding the implicit default exchange bindings
+    pub binding_count: usize,
+    pub connection_count: usize,
+    /// The total number of messages in all queues and streams in the virtual host
+    pub message_count: u64,
+}
+
+/// The outcome of `Client#grow_quorum_queue_membership` and `Client#shrink_quorum_queue_membership`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct QuorumMembershipChange {
+    pub vhost: String,
+    pub queue: String,
+    /// The node that was added or removed
+    pub node: String,
+    /// Quorum queue members (replica nodes) after the change
+    pub members: NodeList,
+}
+
+/// The outcome of `Client#purge_queues`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PurgeReport {
+    /// Names of the queues that were purged
+    pub purged: Vec<String>,
+    /// Names of the queues that did not exist
+    pub not_found: Vec<String>,
+}
+
+/// The number of queue leaders hosted by each cluster node, keyed by node name.
+pub type QueueLeaderDistribution = BTreeMap<String, usize>;
+
+/// Computes how many queue (and stream) leaders each node hosts.
+///
+/// Non-replicated queues are counted towards the node that hosts them.
+pub fn queue_leader_distribution(queues: &[QueueInfo]) -> QueueLeaderDistribution {
+    let mut distribution = QueueLeaderDistribution::new();
+    for q in queues {
+        let node = q.leader.as_ref().unwrap_or(&q.node);
+        *distribution.entry(node.clone()).or_insert(0) += 1;
+    }
+    distribution
+}
+
+/// The outcome of a [queue leader rebalancing](https://www.rabbitmq.com/docs/quorum-queues#replica-rebalancing)
+/// performed with `Client#rebalance_queue_leaders_and_wait`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RebalanceResult {
+    /// Leader distribution observed before the rebalancing was triggered
+    pub before: QueueLeaderDistribution,
+    /// Leader distribution observed by the last poll
+    pub after: QueueLeaderDistribution,
+    /// `true` if leader placement was stable across two consecutive polls,
+    /// `false` if the timeout elapsed first
+    pub stabilized: bool,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct EnforcedLimits(pub Map<String, serde_json::Value>);
 
@@ -434,6 +684,28 @@ pub struct VirtualHostLimits {
     pub limits: EnforcedLimits,
 }
 
+/// Limits of a virtual host, keyed by limit kind. `None` means that the limit is not set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectiveVhostLimits {
+    pub max_connections: Option<i64>,
+    pub max_queues: Option<i64>,
+}
+
+impl EffectiveVhostLimits {
+    pub fn from_limits(limits: &[VirtualHostLimits]) -> Self {
+        let find = |target: VirtualHostLimitTarget| {
+            limits
+                .iter()
+                .find_map(|l| l.limits.get(target.as_ref()).and_then(|v| v.as_i64()))
+        };
+
+        EffectiveVhostLimits {
+            max_connections: find(VirtualHostLimitTarget::MaxConnections),
+            max_queues: find(VirtualHostLimitTarget::MaxQueues),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
@@ -444,6 +716,28 @@ pub struct UserLimits {
     pub limits: EnforcedLimits,
 }
 
+/// Limits of a user, keyed by limit kind. `None` means that the limit is not set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectiveUserLimits {
+    pub max_connections: Option<i64>,
+    pub max_channels: Option<i64>,
+}
+
+impl EffectiveUserLimits {
+    pub fn from_limits(limits: &[UserLimits]) -> Self {
+        let find = |target: UserLimitTarget| {
+            limits
+                .iter()
+                .find_map(|l| l.limits.get(target.as_ref()).and_then(|v| v.as_i64()))
+        };
+
+        EffectiveUserLimits {
+            max_connections: find(UserLimitTarget::MaxConnections),
+            max_channels: find(UserLimitTarget::MaxChannels),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
@@ -453,6 +747,27 @@ pub struct User {
     pub password_hash: String,
 }
 
+/// The user the client authenticates as, as returned by `GET /api/whoami`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct CurrentUser {
+    pub name: String,
+    pub tags: TagList,
+}
+
+impl CurrentUser {
+    /// Returns true if the user has the given tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Returns true if the user is tagged as an `administrator`.
+    pub fn is_administrator(&self) -> bool {
+        self.has_tag("administrator")
+    }
+}
+
 /// Represents a client connection.
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
@@ -465,8 +780,12 @@ pub struct Connection {
     /// Connection state
     #[serde(default = "undefined")]
     pub state: String,
-    /// What protocol the connection uses
+    /// What protocol the connection uses, e.g. `AMQP 0-9-1` or `RabbitMQ Stream`
+    #[serde(default)]
     pub protocol: String,
+    /// Whether TLS is used by this connection
+    #[serde(default)]
+    pub ssl: bool,
     /// The name of the authenticated user
     #[serde(rename(deserialize = "user"))]
     pub username: String,
@@ -495,6 +814,71 @@ pub struct Connection {
     pub client_properties: ClientProperties,
 }
 
+impl Connection {
+    /// Returns for how long this connection has been open.
+    ///
+    /// Returns `None` if the connection timestamp is in the future, e.g. because
+    /// of a clock skew between this host and the node.
+    pub fn connected_duration(&self) -> Option<Duration> {
+        self.connected_duration_at(SystemTime::now())
+    }
+
+    /// Like [`Connection::connected_duration`] but computed relative to the given moment in time
+    /// instead of the current time.
+    pub fn connected_duration_at(&self, now: SystemTime) -> Option<Duration> {
+        let connected_at = UNIX_EPOCH + Duration::from_millis(self.connected_at);
+        now.duration_since(connected_at).ok()
+    }
+
+    /// Returns true if the connection was [blocked](https://rabbitmq.com/docs/alarms/)
+    /// by a resource alarm.
+    ///
+    /// Connections in the `blocking` state are not considered blocked: they have not
+    /// published anything since the alarm went off.
+    pub fn is_blocked(&self) -> bool {
+        self.state == "blocked"
+    }
+
+    /// Returns true if this connection uses the given protocol.
+    ///
+    /// [`SupportedProtocol::AMQP`] matches both AMQP 0-9-1 and AMQP 1.0 connections
+    /// since the two protocols share a listener. The TLS variants only match
+    /// connections that use TLS, and vice versa. Connections that do not report
+    /// a protocol never match.
+    pub fn uses_protocol(&self, protocol: &SupportedProtocol) -> bool {
+        if self.protocol.is_empty() {
+            return false;
+        }
+
+        let name = self.protocol.as_str();
+        match protocol {
+            SupportedProtocol::AMQP => !self.ssl && name.starts_with("AMQP"),
+            SupportedProtocol::AMQPWithTLS => self.ssl && name.starts_with("AMQP"),
+            SupportedProtocol::Stream => !self.ssl && name.starts_with("RabbitMQ Stream"),
+            SupportedProtocol::StreamWithTLS => self.ssl && name.starts_with("RabbitMQ Stream"),
+            SupportedProtocol::MQTT => !self.ssl && name.starts_with("MQTT"),
+            SupportedProtocol::MQTTWithTLS => self.ssl && name.starts_with("MQTT"),
+            SupportedProtocol::STOMP => !self.ssl && name.starts_with("STOMP"),
+            SupportedProtocol::STOMPWithTLS => self.ssl && name.starts_with("STOMP"),
+            SupportedProtocol::MQTTOverWebSockets => !self.ssl && name.starts_with("Web MQTT"),
+            SupportedProtocol::MQTTOverWebSocketsWithTLS => {
+                self.ssl && name.starts_with("Web MQTT")
+            }
+            SupportedProtocol::STOMPOverWebsockets => !self.ssl && name.starts_with("Web STOMP"),
+            SupportedProtocol::STOMPOverWebsocketsWithTLS => {
+                self.ssl && name.starts_with("Web STOMP")
+            }
+            SupportedProtocol::Other(other) => name == other,
+            // these listeners do not have client connections
+            SupportedProtocol::Clustering
+            | SupportedProtocol::Prometheus
+            | SupportedProtocol::PrometheusWithTLS
+            | SupportedProtocol::HTTP
+            | SupportedProtocol::HTTPWithTLS => false,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct ClientProperties {
@@ -512,15 +896,23 @@ pub struct ClientProperties {
 #[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct ClientCapabilities {
+    // clients can advertise any subset of these (or none at all),
+    // so a missing capability is considered to be unsupported
+    #[serde(default)]
     pub authentication_failure_close: bool,
     #[serde(rename(deserialize = "basic.nack"))]
+    #[serde(default)]
     pub basic_nack: bool,
     #[serde(rename(deserialize = "connection.blocked"))]
+    #[serde(default)]
     pub connection_blocked: bool,
     #[serde(rename(deserialize = "consumer_cancel_notify"))]
+    #[serde(default)]
     pub consumer_cancel_notify: bool,
     #[serde(rename(deserialize = "exchange_exchange_bindings"))]
+    #[serde(default)]
     pub exchange_to_exchange_bindings: bool,
+    #[serde(default)]
     pub publisher_confirms: bool,
 }
 
@@ -549,9 +941,28 @@ pub struct Channel {
     pub consumer_count: u32,
     #[serde(rename(deserialize = "confirm"))]
     pub has_publisher_confirms_enabled: bool,
+    /// Per-consumer prefetch (QoS) limit, 0 means "no limit"
+    #[serde(default)]
     pub prefetch_count: u32,
+    /// Channel-wide ("global") prefetch (QoS) limit, 0 means "not set"
+    #[serde(default)]
+    pub global_prefetch_count: u32,
+    #[serde(default)]
     pub messages_unacknowledged: u32,
+    #[serde(default)]
     pub messages_unconfirmed: u32,
+    /// Not available when management metrics collection is disabled
+    /// or there has been no activity on the channel yet
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_stats: Option<MessageStats>,
+}
+
+impl Channel {
+    /// Returns true if a channel-wide ("global") prefetch limit is set on this channel.
+    pub fn has_global_prefetch(&self) -> bool {
+        self.global_prefetch_count > 0
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -648,6 +1059,26 @@ impl fmt::Display for NameAndVirtualHost {
     }
 }
 
+/// Message and consumer counts of a queue or stream, see `Client#get_queue_depth`.
+///
+/// Only these fields are deserialized, which makes this type cheaper to use
+/// than [`QueueInfo`] when polling queues in a tight loop.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct QueueDepth {
+    /// Total number of messages
+    #[serde(default)]
+    pub messages: u64,
+    /// Number of messages ready for delivery
+    #[serde(default)]
+    pub messages_ready: u64,
+    /// Number of delivered but unacknowledged messages
+    #[serde(default)]
+    pub messages_unacknowledged: u64,
+    #[serde(default)]
+    pub consumers: u16,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
@@ -656,9 +1087,15 @@ pub struct QueueInfo {
     pub vhost: String,
     #[serde(rename(deserialize = "type"))]
     pub queue_type: String,
+    // streams are always durable, and some versions omit
+    // these properties for them
+    #[serde(default = "default_true")]
     pub durable: bool,
+    #[serde(default)]
     pub auto_delete: bool,
+    #[serde(default)]
     pub exclusive: bool,
+    #[serde(default)]
     #[cfg_attr(feature = "tabled", tabled(display_with = "display_arg_table"))]
     pub arguments: XArguments,
 
@@ -673,13 +1110,19 @@ pub struct QueueInfo {
     pub members: Option<NodeList>,
     #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
     pub online: Option<NodeList>,
+    // only classic mirrored queues (RabbitMQ 3.x) will have these two
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub slave_nodes: Option<NodeList>,
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub synchronised_slave_nodes: Option<NodeList>,
 
-    #[serde(default)]
+    // streams can report a null or omit these
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub memory: u64,
     #[serde(rename(deserialize = "consumers"))]
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub consumer_count: u16,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_default_from_null")]
     pub consumer_utilisation: f32,
     #[cfg_attr(feature = "tabled", tabled(skip))]
     pub exclusive_consumer_tag: Option<String>,
@@ -716,6 +1159,84 @@ pub struct QueueInfo {
     #[serde(rename(deserialize = "messages_unacknowledged"))]
     #[serde(default)]
     pub unacknowledged_message_count: u64,
+
+    /// Not available when management metrics collection is disabled
+    /// or there has been no activity on the queue yet
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub message_stats: Option<MessageStats>,
+}
+
+impl QueueInfo {
+    /// Returns a normalized view of replica placement that works the same way
+    /// for quorum queues, streams and classic mirrored queues.
+    ///
+    /// For non-replicated classic queues, the hosting node is reported as the leader
+    /// and both replica lists are empty.
+    pub fn replica_status(&self) -> ReplicaStatus {
+        match (&self.members, &self.slave_nodes) {
+            (Some(members), _) => {
+                let online = self
+                    .online
+                    .as_ref()
+                    .map(|ns| ns.0.as_slice())
+                    .unwrap_or_default();
+                let followers = members
+                    .0
+                    .iter()
+                    .filter(|n| self.leader.as_ref() != Some(*n));
+                let (in_sync, out_of_sync): (Vec<String>, Vec<String>) =
+                    followers.cloned().partition(|n| online.contains(n));
+
+                ReplicaStatus {
+                    leader: self.leader.clone(),
+                    in_sync_replicas: in_sync,
+                    out_of_sync_replicas: out_of_sync,
+                }
+            }
+            (None, Some(mirrors)) => {
+                let synchronised = self
+                    .synchronised_slave_nodes
+                    .as_ref()
+                    .map(|ns| ns.0.as_slice())
+                    .unwrap_or_default();
+                let (in_sync, out_of_sync): (Vec<String>, Vec<String>) = mirrors
+                    .0
+                    .iter()
+                    .cloned()
+                    .partition(|n| synchronised.contains(n));
+
+                ReplicaStatus {
+                    leader: Some(self.node.clone()),
+                    in_sync_replicas: in_sync,
+                    out_of_sync_replicas: out_of_sync,
+                }
+            }
+            (None, None) => ReplicaStatus {
+                leader: Some(self.node.clone()),
+                in_sync_replicas: vec![],
+                out_of_sync_replicas: vec![],
+            },
+        }
+    }
+}
+
+/// Replica placement of a queue or stream, see [`QueueInfo::replica_status`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReplicaStatus {
+    /// The node hosting the leader replica (or the only replica)
+    pub leader: Option<String>,
+    /// Followers (mirrors) that are online and in sync with the leader
+    pub in_sync_replicas: Vec<String>,
+    /// Followers (mirrors) that are offline or not (yet) in sync with the leader
+    pub out_of_sync_replicas: Vec<String>,
+}
+
+impl ReplicaStatus {
+    /// Returns true if all replicas are in sync with the leader.
+    pub fn is_fully_in_sync(&self) -> bool {
+        self.out_of_sync_replicas.is_empty()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -745,6 +1266,66 @@ pub struct ExchangeInfo {
 }
 type ExchangeDefinition = ExchangeInfo;
 
+impl ExchangeInfo {
+    /// Returns true if this is the default exchange or one of the `amq.*` exchanges
+    /// that RabbitMQ declares in every virtual host.
+    pub fn is_built_in(&self) -> bool {
+        self.name.is_empty() || self.name.starts_with("amq.")
+    }
+}
+
+/// Same as [`ExchangeInfo`] but also includes message rates.
+#[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct ExchangeInfoWithRates {
+    pub name: String,
+    pub vhost: String,
+    #[serde(rename = "type")]
+    pub exchange_type: String,
+    pub durable: bool,
+    pub auto_delete: bool,
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_arg_table"))]
+    pub arguments: XArguments,
+    /// Not available when management metrics collection is disabled
+    /// or no messages have been published to (or routed by) the exchange yet
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub message_stats: Option<ExchangeMessageStats>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct ExchangeMessageStats {
+    /// Messages published to this exchange
+    #[serde(default)]
+    pub publish_in: u64,
+    #[serde(default)]
+    pub publish_in_details: Rate,
+    /// Messages routed by this exchange to queues, streams, or other exchanges
+    #[serde(default)]
+    pub publish_out: u64,
+    #[serde(default)]
+    pub publish_out_details: Rate,
+}
+
+impl fmt::Display for ExchangeMessageStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Published in: {} (rate: {}/s)",
+            self.publish_in, self.publish_in_details.rate
+        )?;
+        writeln!(
+            f,
+            "Published out: {} (rate: {}/s)",
+            self.publish_out, self.publish_out_details.rate
+        )?;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
@@ -760,30 +1341,180 @@ pub struct BindingInfo {
     pub properties_key: Option<String>,
 }
 
+impl BindingInfo {
+    /// Returns true if this is one of the implicit bindings of the default exchange,
+    /// which every queue has and which cannot be deleted.
+    pub fn is_default_exchange_binding(&self) -> bool {
+        self.source.is_empty()
+    }
+
+    /// Returns true if the destination of this binding is a queue or stream.
+    pub fn is_queue_binding(&self) -> bool {
+        self.destination_type == BindingDestinationType::Queue
+    }
+
+    /// Returns true if this is an [exchange-to-exchange binding](https://rabbitmq.com/docs/e2e/).
+    pub fn is_exchange_to_exchange_binding(&self) -> bool {
+        self.destination_type == BindingDestinationType::Exchange
+    }
+
+    /// Returns true if both bindings have the same identity: virtual host, source, destination,
+    /// destination type, routing key and arguments. The properties key is not compared.
+    ///
+    /// This is the same comparison `Client#delete_binding` uses to locate a binding.
+    pub fn has_same_identity_as(&self, other: &BindingInfo) -> bool {
+        self.vhost == other.vhost
+            && self.source == other.source
+            && self.destination == other.destination
+            && self.destination_type == other.destination_type
+            && self.routing_key == other.routing_key
+            && self.arguments.0 == other.arguments.0
+    }
+}
+
+/// A vertex of a [`RoutingGraph`]: an exchange, a queue or a stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    pub name: String,
+    /// Whether this node is an exchange or a queue (stream)
+    pub kind: BindingDestinationType,
+}
+
+/// An edge of a [`RoutingGraph`], that is, a binding.
+#[derive(Debug, Clone)]
+pub struct GraphEdge {
+    /// The source exchange
+    pub source: String,
+    pub destination: String,
+    pub destination_type: BindingDestinationType,
+    pub routing_key: String,
+    pub arguments: XArguments,
+}
+
+/// The routing topology of a virtual host: exchanges, queues and streams are the nodes,
+/// bindings are the edges.
+///
+/// Only the objects that have at least one binding are included.
+/// The implicit default exchange bindings are omitted.
+#[derive(Debug, Clone, Default)]
+pub struct RoutingGraph {
+    /// Nodes in the order they were first seen in the bindings
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl RoutingGraph {
+    /// Builds a routing graph from a list of bindings.
+    pub fn from_bindings(bindings: &[BindingInfo]) -> Self {
+        let mut graph = RoutingGraph::default();
+        for b in bindings.iter().filter(|b| !b.is_default_exchange_binding()) {
+            graph.add_node(&b.source, BindingDestinationType::Exchange);
+            graph.add_node(&b.destination, b.destination_type.clone());
+            graph.edges.push(GraphEdge {
+                source: b.source.clone(),
+                destination: b.destination.clone(),
+                destination_type: b.destination_type.clone(),
+                routing_key: b.routing_key.clone(),
+                arguments: b.arguments.clone(),
+            });
+        }
+        graph
+    }
+
+    /// Returns the edges that start at the given exchange.
+    pub fn edges_from<'a>(&'a self, exchange: &'a str) -> impl Iterator<Item = &'a GraphEdge> {
+        self.edges.iter().filter(move |e| e.source == exchange)
+    }
+
+    fn add_node(&mut self, name: &str, kind: BindingDestinationType) {
+        if !self.nodes.iter().any(|n| n.name == name && n.kind == kind) {
+            self.nodes.push(GraphNode {
+                name: name.to_owned(),
+                kind,
+            });
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
 pub struct ClusterNode {
     pub name: String,
+    /// Stopped (unreachable) cluster members are listed with `running` set to `false`
+    /// and without most of the metrics below
+    #[serde(default = "default_true")]
+    pub running: bool,
+    #[serde(default)]
     pub uptime: u32,
+    #[serde(default)]
     pub run_queue: u32,
+    #[serde(default)]
     pub processors: u32,
-    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub os_pid: u32,
+    #[serde(default)]
     pub fd_total: u32,
-    #[serde(rename(deserialize = "proc_total"))]
+    #[serde(rename(deserialize = "proc_total"), default)]
     pub total_erlang_processes: u32,
-    #[serde(rename(deserialize = "mem_limit"))]
+    #[serde(rename(deserialize = "mem_limit"), default)]
     pub memory_high_watermark: u64,
-    #[serde(rename(deserialize = "mem_alarm"))]
+    #[serde(rename(deserialize = "mem_alarm"), default)]
     pub has_memory_alarm_in_effect: bool,
-    #[serde(rename(deserialize = "disk_free_limit"))]
+    #[serde(rename(deserialize = "disk_free_limit"), default)]
     pub free_disk_space_low_watermark: u64,
-    #[serde(rename(deserialize = "disk_free_alarm"))]
+    #[serde(rename(deserialize = "disk_free_alarm"), default)]
     pub has_free_disk_space_alarm_in_effect: bool,
+    #[serde(default)]
     pub rates_mode: String,
+    #[serde(default)]
     pub enabled_plugins: PluginList,
+    #[serde(default)]
     pub being_drained: bool,
+    /// Only included when requested with [`crate::requests::NodeInfoFlags::memory`]
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub memory: Option<NodeMemoryBreakdown>,
+    /// Binary heap usage by category, only included when requested
+    /// with [`crate::requests::NodeInfoFlags::binary`]
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub binary: Option<BTreeMap<String, u64>>,
+    /// Only included when requested with [`crate::requests::NodeInfoFlags::ets`]
+    #[serde(default, rename = "ets_tables_memory")]
+    #[cfg_attr(feature = "tabled", tabled(skip))]
+    pub ets_tables: Option<NodeEtsMemoryBreakdown>,
+}
+
+/// A quick view of cluster membership health, computed from [`ClusterNode`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterSummary {
+    /// The number of cluster members
+    pub total: usize,
+    /// The number of cluster members that are running
+    pub running: usize,
+    /// The number of cluster members in (or entering) maintenance mode
+    pub in_maintenance: usize,
+    /// Names of all cluster members
+    pub names: Vec<String>,
+}
+
+impl ClusterSummary {
+    /// Returns `true` if all cluster members are running and none are in maintenance mode.
+    pub fn is_fully_operational(&self) -> bool {
+        self.running == self.total && self.in_maintenance == 0
+    }
+}
+
+impl From<&[ClusterNode]> for ClusterSummary {
+    fn from(nodes: &[ClusterNode]) -> Self {
+        ClusterSummary {
+            total: nodes.len(),
+            running: nodes.iter().filter(|n| n.running).count(),
+            in_maintenance: nodes.iter().filter(|n| n.being_drained).count(),
+            names: nodes.iter().map(|n| n.name.clone()).collect(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -806,6 +1537,18 @@ pub struct ClusterIdentity {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PolicyDefinition(pub Option<Map<String, serde_json::Value>>);
 
+impl PolicyDefinition {
+    /// Returns the [overflow behavior](https://rabbitmq.com/docs/maxlength/#overflow-behaviour)
+    /// set by this policy, if any.
+    pub fn overflow_behavior(&self) -> Option<OverflowBehavior> {
+        self.0
+            .as_ref()
+            .and_then(|m| m.get("overflow"))
+            .and_then(|v| v.as_str())
+            .map(OverflowBehavior::from)
+    }
+}
+
 impl fmt::Display for PolicyDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let maybe_val = self.0.clone();
@@ -840,29 +1583,272 @@ pub struct Permissions {
     pub write: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// [Topic permissions](https://rabbitmq.com/docs/access-control#topic-authorisation)
+/// of a user on a topic exchange in a virtual host.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
 #[allow(dead_code)]
-pub struct DefinitionSet {
-    #[serde(rename(deserialize = "rabbitmq_version"))]
-    pub server_version: String,
-    pub users: Vec<User>,
-    #[serde(rename(deserialize = "vhosts"))]
-    pub virtual_hosts: Vec<VirtualHost>,
-    pub permissions: Vec<Permissions>,
-
+pub struct TopicPermission {
+    pub user: String,
+    pub vhost: String,
+    pub exchange: String,
+    pub read: String,
+    pub write: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct DefinitionSet {
+    /// The `rabbitmq_version` of the node the definitions were exported from
+    #[serde(rename(deserialize = "rabbitmq_version"), default)]
+    pub server_version: String,
+    /// The legacy `rabbit_version` key, still included into exports for compatibility
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rabbit_version: Option<String>,
+    /// The name of the product (distribution) the definitions were exported from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_name: Option<String>,
+    /// The version of the product (distribution) the definitions were exported from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_version: Option<String>,
+    // nodes can omit empty sections, e.g. when exporting definitions of an empty virtual host
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(rename(deserialize = "vhosts"), default)]
+    pub virtual_hosts: Vec<VirtualHost>,
+    #[serde(default)]
+    pub permissions: Vec<Permissions>,
+
+    #[serde(default)]
     pub parameters: Vec<RuntimeParameter>,
+    #[serde(default)]
     pub policies: Vec<Policy>,
 
+    #[serde(default)]
     pub queues: Vec<QueueDefinition>,
+    #[serde(default)]
     pub exchanges: Vec<ExchangeDefinition>,
+    #[serde(default)]
     pub bindings: Vec<BindingInfo>,
 }
 
+impl DefinitionSet {
+    /// Returns the RabbitMQ version the definitions were exported from,
+    /// using `rabbitmq_version` and falling back to `rabbit_version`.
+    ///
+    /// Returns `None` if neither is present or can be parsed.
+    pub fn source_version(&self) -> Option<RabbitMqVersion> {
+        RabbitMqVersion::parse(&self.server_version).or_else(|| {
+            self.rabbit_version
+                .as_deref()
+                .and_then(RabbitMqVersion::parse)
+        })
+    }
+
+    /// Returns the names of all virtual hosts this definition set
+    /// declares or references (in queues, exchanges and bindings).
+    pub fn virtual_host_names(&self) -> HashSet<&str> {
+        self.virtual_hosts
+            .iter()
+            .map(|vh| vh.name.as_str())
+            .chain(self.queues.iter().map(|q| q.vhost.as_str()))
+            .chain(self.exchanges.iter().map(|x| x.vhost.as_str()))
+            .chain(self.bindings.iter().map(|b| b.vhost.as_str()))
+            .collect()
+    }
+}
+
+/// The drift between a [`DefinitionSet`] (the intended state) and the live cluster,
+/// as computed by `Client#reconcile_against_definitions`.
+///
+/// Queues and exchanges are identified by virtual host and name, bindings
+/// are compared using [`BindingInfo#has_same_identity_as`](BindingInfo::has_same_identity_as).
+///
+/// Only the virtual hosts declared or referenced by the definition set are compared:
+/// objects in other virtual hosts are not reported as unexpected. Objects that are never
+/// exported as definitions (the default exchange and its bindings, `amq.*` exchanges,
+/// exclusive queues) are not reported either.
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationReport {
+    /// Queues in the definition set that do not exist in the cluster
+    pub missing_queues: Vec<QueueDefinition>,
+    /// Queues in the cluster that are not in the definition set
+    pub unexpected_queues: Vec<QueueInfo>,
+    /// Exchanges in the definition set that do not exist in the cluster
+    pub missing_exchanges: Vec<ExchangeInfo>,
+    /// Exchanges in the cluster that are not in the definition set
+    pub unexpected_exchanges: Vec<ExchangeInfo>,
+    /// Bindings in the definition set that do not exist in the cluster
+    pub missing_bindings: Vec<BindingInfo>,
+    /// Bindings in the cluster that are not in the definition set
+    pub unexpected_bindings: Vec<BindingInfo>,
+}
+
+impl ReconciliationReport {
+    /// Compares the intended state with the live queues, exchanges and bindings.
+    pub fn compute(
+        definitions: &DefinitionSet,
+        queues: Vec<QueueInfo>,
+        exchanges: Vec<ExchangeInfo>,
+        bindings: Vec<BindingInfo>,
+    ) -> Self {
+        let vhosts = definitions.virtual_host_names();
+
+        let missing_queues = definitions
+            .queues
+            .iter()
+            .filter(|d| {
+                !queues
+                    .iter()
+                    .any(|q| q.vhost == d.vhost && q.name == d.name)
+            })
+            .cloned()
+            .collect();
+        let unexpected_queues = queues
+            .into_iter()
+            .filter(|q| vhosts.contains(q.vhost.as_str()) && !q.exclusive)
+            .filter(|q| {
+                !definitions
+                    .queues
+                    .iter()
+                    .any(|d| d.vhost == q.vhost && d.name == q.name)
+            })
+            .collect();
+
+        let missing_exchanges = definitions
+            .exchanges
+            .iter()
+            .filter(|d| {
+                !exchanges
+                    .iter()
+                    .any(|x| x.vhost == d.vhost && x.name == d.name)
+            })
+            .cloned()
+            .collect();
+        let unexpected_exchanges = exchanges
+            .into_iter()
+            .filter(|x| vhosts.contains(x.vhost.as_str()))
+            .filter(|x| !x.name.is_empty() && !x.name.starts_with("amq."))
+            .filter(|x| {
+                !definitions
+                    .exchanges
+                    .iter()
+                    .any(|d| d.vhost == x.vhost && d.name == x.name)
+            })
+            .collect();
+
+        let missing_bindings = definitions
+            .bindings
+            .iter()
+            .filter(|d| !bindings.iter().any(|b| b.has_same_identity_as(d)))
+            .cloned()
+            .collect();
+        let unexpected_bindings = bindings
+            .into_iter()
+            .filter(|b| vhosts.contains(b.vhost.as_str()) && !b.is_default_exchange_binding())
+            .filter(|b| {
+                !definitions
+                    .bindings
+                    .iter()
+                    .any(|d| d.has_same_identity_as(b))
+            })
+            .collect();
+
+        ReconciliationReport {
+            missing_queues,
+            unexpected_queues,
+            missing_exchanges,
+            unexpected_exchanges,
+            missing_bindings,
+            unexpected_bindings,
+        }
+    }
+
+    /// Returns true if the live cluster matches the definition set.
+    pub fn is_in_sync(&self) -> bool {
+        self.missing_queues.is_empty()
+            && self.unexpected_queues.is_empty()
+            && self.missing_exchanges.is_empty()
+            && self.unexpected_exchanges.is_empty()
+            && self.missing_bindings.is_empty()
+            && self.unexpected_bindings.is_empty()
+    }
+}
+
+/// What importing a [`DefinitionSet`] would do to the cluster,
+/// as computed by `Client#plan_definition_import`.
+///
+/// Queues, exchanges and policies are identified by virtual host and name.
+/// Objects that already exist in the cluster will be overwritten by the import.
+#[derive(Debug, Clone, Default)]
+pub struct DefinitionImportPlan {
+    /// Queues in the incoming definition set that do not exist in the cluster
+    pub queues_to_create: Vec<QueueDefinition>,
+    /// Queues in the incoming definition set that already exist in the cluster
+    pub queues_to_overwrite: Vec<QueueDefinition>,
+    /// Exchanges in the incoming definition set that do not exist in the cluster
+    pub exchanges_to_create: Vec<ExchangeInfo>,
+    /// Exchanges in the incoming definition set that already exist in the cluster
+    pub exchanges_to_overwrite: Vec<ExchangeInfo>,
+    /// Policies in the incoming definition set that do not exist in the cluster
+    pub policies_to_create: Vec<Policy>,
+    /// Policies in the incoming definition set that already exist in the cluster
+    pub policies_to_overwrite: Vec<Policy>,
+}
+
+impl DefinitionImportPlan {
+    /// Compares the incoming definition set with the current state of the cluster.
+    pub fn compute(incoming: &DefinitionSet, current: &DefinitionSet) -> Self {
+        let (queues_to_overwrite, queues_to_create) =
+            incoming.queues.iter().cloned().partition(|d| {
+                current
+                    .queues
+                    .iter()
+                    .any(|q| q.vhost == d.vhost && q.name == d.name)
+            });
+        let (exchanges_to_overwrite, exchanges_to_create) =
+            incoming.exchanges.iter().cloned().partition(|d| {
+                current
+                    .exchanges
+                    .iter()
+                    .any(|x| x.vhost == d.vhost && x.name == d.name)
+            });
+        let (policies_to_overwrite, policies_to_create) =
+            incoming.policies.iter().cloned().partition(|d| {
+                current
+                    .policies
+                    .iter()
+                    .any(|p| p.vhost == d.vhost && p.name == d.name)
+            });
+
+        DefinitionImportPlan {
+            queues_to_create,
+            queues_to_overwrite,
+            exchanges_to_create,
+            exchanges_to_overwrite,
+            policies_to_create,
+            policies_to_overwrite,
+        }
+    }
+
+    /// Returns true if the import would overwrite any existing objects.
+    pub fn has_overwrites(&self) -> bool {
+        !self.queues_to_overwrite.is_empty()
+            || !self.exchanges_to_overwrite.is_empty()
+            || !self.policies_to_overwrite.is_empty()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum HealthCheckFailureDetails {
     AlarmCheck(ClusterAlarmCheckDetails),
     NodeIsQuorumCritical(QuorumCriticalityCheckDetails),
+    // must come before the listener variants: their listener field is optional,
+    // so they would match this response as well
+    CertificatesExpiring(CertificateExpirationCheckDetails),
+    NodeConnectionLimitReached(NodeConnectionLimitCheckDetails),
+    MetadataStoreNotInitialized(MetadataStoreInitializationCheckDetails),
     NoActivePortListener(NoActivePortListenerDetails),
     NoActiveProtocolListener(NoActiveProtocolListenerDetails),
 }
@@ -872,6 +1858,13 @@ impl HealthCheckFailureDetails {
         match self {
             HealthCheckFailureDetails::AlarmCheck(details) => details.reason.clone(),
             HealthCheckFailureDetails::NodeIsQuorumCritical(details) => details.reason.clone(),
+            HealthCheckFailureDetails::CertificatesExpiring(details) => details.reason.clone(),
+            HealthCheckFailureDetails::NodeConnectionLimitReached(details) => {
+                details.reason.clone()
+            }
+            HealthCheckFailureDetails::MetadataStoreNotInitialized(details) => {
+                details.reason.clone()
+            }
             HealthCheckFailureDetails::NoActivePortListener(details) => details.reason.clone(),
             HealthCheckFailureDetails::NoActiveProtocolListener(details) => details.reason.clone(),
         }
@@ -896,6 +1889,77 @@ pub struct QuorumCriticalityCheckDetails {
     pub queues: Vec<QuorumEndangeredQueue>,
 }
 
+/// The outcome of `Client#node_is_quorum_critical`.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct QuorumCriticalityReport {
+    /// `true` if shutting down the node would make some quorum queues or streams lose their quorum
+    pub is_critical: bool,
+    /// The queues and streams that would lose their quorum, empty if the node is not critical
+    pub queues: Vec<QuorumEndangeredQueue>,
+}
+
+impl From<QuorumCriticalityCheckDetails> for QuorumCriticalityReport {
+    fn from(details: QuorumCriticalityCheckDetails) -> Self {
+        QuorumCriticalityReport {
+            is_critical: true,
+            queues: details.queues,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct CertificateExpirationCheckDetails {
+    pub status: String,
+    pub reason: String,
+    /// Listeners with certificates that expire within the checked period
+    #[serde(rename(deserialize = "expired"))]
+    pub expiring_listeners: Vec<ExpiringListener>,
+}
+
+/// A TLS-enabled listener with a certificate that expires soon.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct ExpiringListener {
+    pub node: String,
+    pub protocol: String,
+    #[serde(default)]
+    pub interface: String,
+    pub port: u16,
+    #[serde(default)]
+    pub certfile: String,
+    #[serde(default)]
+    pub cacertfile: String,
+    #[serde(default)]
+    pub expires: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct NodeConnectionLimitCheckDetails {
+    pub status: String,
+    pub reason: String,
+    /// The limit used by the check
+    pub limit: u32,
+    /// The number of client connections on the node
+    #[serde(rename(deserialize = "connections"))]
+    pub connection_count: u32,
+}
+
+impl NodeConnectionLimitCheckDetails {
+    /// How many connections the node has over the limit
+    pub fn connections_over_limit(&self) -> u32 {
+        self.connection_count.saturating_sub(self.limit)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+pub struct MetadataStoreInitializationCheckDetails {
+    pub status: String,
+    pub reason: String,
+    /// The metadata store used by the node, `khepri` or `mnesia`
+    pub metadata_store: String,
+    /// Whether the metadata store has finished initializing
+    pub initialized: bool,
+}
+
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
 pub struct NoActivePortListenerDetails {
     pub status: String,
@@ -941,53 +2005,139 @@ pub struct GetMessage {
     pub payload_encoding: String,
 }
 
+impl GetMessage {
+    /// Returns the payload as bytes, decoding it according to `payload_encoding`.
+    ///
+    /// Payloads that are not valid UTF-8 are returned by the HTTP API Base64-encoded.
+    /// Returns `None` if such a payload is not valid Base64.
+    pub fn decoded_payload(&self) -> Option<Vec<u8>> {
+        match self.payload_encoding.as_str() {
+            "base64" => rbase64::decode(&self.payload).ok(),
+            _ => Some(self.payload.as_bytes().to_vec()),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(transparent)]
 pub struct MessageList(pub Vec<GetMessage>);
 
+/// Returned by `Client#publish_message`.
+///
+/// Note that this only reflects whether the message was routed to at least one
+/// queue or stream. It says nothing about whether the message was delivered
+/// to or consumed (processed) by any consumers.
 #[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct MessageRouted {
     pub routed: bool,
 }
 
+impl MessageRouted {
+    /// Returns true if the message was routed to at least one queue or stream.
+    pub fn was_routed(&self) -> bool {
+        self.routed
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Default)]
 #[serde(transparent)]
 pub struct MessageProperties(pub Map<String, serde_json::Value>);
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+/// Connection, channel and queue churn: total counts of created and closed (deleted)
+/// objects plus their per second rates.
+///
+/// Counters and rates missing from the payload (e.g. reported by older RabbitMQ versions)
+/// default to zero.
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[serde(default)]
 pub struct ChurnRates {
     pub connection_created: u32,
+    pub connection_created_details: Rate,
     pub connection_closed: u32,
+    pub connection_closed_details: Rate,
     pub queue_declared: u32,
+    pub queue_declared_details: Rate,
     pub queue_created: u32,
+    pub queue_created_details: Rate,
     pub queue_deleted: u32,
+    pub queue_deleted_details: Rate,
     pub channel_created: u32,
+    pub channel_created_details: Rate,
     pub channel_closed: u32,
+    pub channel_closed_details: Rate,
+}
+
+impl ChurnRates {
+    /// Returns the combined rate of connections opened and closed per second.
+    /// A sudden spike usually indicates a connection storm.
+    pub fn connection_churn_per_sec(&self) -> f64 {
+        self.connection_created_details.rate + self.connection_closed_details.rate
+    }
+
+    /// Returns the combined rate of channels opened and closed per second.
+    pub fn channel_churn_per_sec(&self) -> f64 {
+        self.channel_created_details.rate + self.channel_closed_details.rate
+    }
+
+    /// Returns the combined rate of queues created and deleted per second.
+    pub fn queue_churn_per_sec(&self) -> f64 {
+        self.queue_created_details.rate + self.queue_deleted_details.rate
+    }
 }
+
 impl fmt::Display for ChurnRates {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "connection_created: {}", self.connection_created)?;
-        writeln!(f, "connection_closed: {}", self.connection_closed)?;
-        writeln!(f, "queue_declared: {}", self.queue_declared)?;
-        writeln!(f, "queue_created: {}", self.queue_created)?;
-        writeln!(f, "queue_deleted: {}", self.queue_deleted)?;
-        writeln!(f, "channel_created: {}", self.channel_created)?;
-        writeln!(f, "channel_closed: {}", self.channel_closed)?;
+        writeln!(
+            f,
+            "connection_created: {} ({:.2}/s)",
+            self.connection_created, self.connection_created_details.rate
+        )?;
+        writeln!(
+            f,
+            "connection_closed: {} ({:.2}/s)",
+            self.connection_closed, self.connection_closed_details.rate
+        )?;
+        writeln!(
+            f,
+            "queue_declared: {} ({:.2}/s)",
+            self.queue_declared, self.queue_declared_details.rate
+        )?;
+        writeln!(
+            f,
+            "queue_created: {} ({:.2}/s)",
+            self.queue_created, self.queue_created_details.rate
+        )?;
+        writeln!(
+            f,
+            "queue_deleted: {} ({:.2}/s)",
+            self.queue_deleted, self.queue_deleted_details.rate
+        )?;
+        writeln!(
+            f,
+            "channel_created: {} ({:.2}/s)",
+            self.channel_created, self.channel_created_details.rate
+        )?;
+        writeln!(
+            f,
+            "channel_closed: {} ({:.2}/s)",
+            self.channel_closed, self.channel_closed_details.rate
+        )?;
 
         Ok(())
     }
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Deserialize, Clone, PartialEq, PartialOrd, Default)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
 pub struct Rate {
     pub rate: f64,
 }
 
-#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq, Default)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[serde(default)]
 pub struct ObjectTotals {
     pub connections: u64,
     pub channels: u64,
@@ -996,8 +2146,9 @@ pub struct ObjectTotals {
     pub consumers: u64,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[serde(default)]
 pub struct QueueTotals {
     pub messages: u64,
     #[serde(rename = "messages_ready")]
@@ -1011,9 +2162,35 @@ pub struct QueueTotals {
     pub messages_delivered_but_unacknowledged_by_consumers_details: Rate,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[serde(default)]
 pub struct MessageStats {
+    /// Messages published
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub publish: Option<u64>,
+    /// Messages delivered to consumers plus messages fetched via polling (`basic.get`)
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub deliver_get: Option<u64>,
+    /// Messages delivered to consumers in automatic acknowledgement mode
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub deliver_no_ack: Option<u64>,
+    /// Messages redelivered
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub redeliver: Option<u64>,
+    /// Messages confirmed to publishers
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub confirm: Option<u64>,
+    /// Messages acknowledged by consumers
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub ack: Option<u64>,
+    /// Unroutable messages dropped
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub drop_unroutable: Option<u64>,
+    /// Unroutable messages returned to publishers
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub return_unroutable: Option<u64>,
+
     /// Consumder delivery rate plus polling (via 'basic.get') rate
     #[serde(rename = "deliver_get_details")]
     pub delivery_details: Rate,
@@ -1061,6 +2238,12 @@ pub struct Overview {
     pub rabbitmq_version: String,
     pub product_name: String,
     pub product_version: String,
+    #[serde(default)]
+    pub management_version: String,
+    /// The statistics mode of the management plugin, determines if rates are reported
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub rates_mode: Option<RatesMode>,
 
     // these two won't be available in 3.13.x
     #[cfg_attr(feature = "tabled", tabled(display_with = "display_tag_map_option"))]
@@ -1069,13 +2252,180 @@ pub struct Overview {
     pub node_tags: Option<TagMap>,
 
     pub statistics_db_event_queue: u64,
-    pub churn_rates: ChurnRates,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub churn_rates: Option<ChurnRates>,
 
+    /// Will be all zeroes if the node does not report queue totals
+    #[serde(default)]
     pub queue_totals: QueueTotals,
+    /// Will be all zeroes if the node does not report object totals
+    #[serde(default)]
     pub object_totals: ObjectTotals,
+    /// Will be all zeroes if the node does not report message stats (e.g. metrics collection is disabled)
+    #[serde(default)]
     pub message_stats: MessageStats,
 }
 
+impl Overview {
+    /// Returns the combined rate of connections opened and closed per second, if available.
+    pub fn connection_churn_per_sec(&self) -> Option<f64> {
+        self.churn_rates
+            .as_ref()
+            .map(ChurnRates::connection_churn_per_sec)
+    }
+
+    /// Returns the total number of messages in all queues and streams in the cluster,
+    /// or `0` if queue totals are not reported.
+    pub fn total_queued_messages(&self) -> u64 {
+        self.queue_totals.messages
+    }
+
+    /// Returns the total number of messages ready for delivery in the cluster,
+    /// or `0` if queue totals are not reported.
+    pub fn total_ready_messages(&self) -> u64 {
+        self.queue_totals.messages_ready_for_delivery
+    }
+
+    /// Returns the total number of delivered but unacknowledged messages in the cluster,
+    /// or `0` if queue totals are not reported.
+    pub fn total_unacknowledged_messages(&self) -> u64 {
+        self.queue_totals
+            .messages_delivered_but_unacknowledged_by_consumers
+    }
+
+    /// Returns the total number of client connections in the cluster,
+    /// or `0` if object totals are not reported.
+    pub fn total_connections(&self) -> u64 {
+        self.object_totals.connections
+    }
+
+    /// Returns the total number of channels in the cluster,
+    /// or `0` if object totals are not reported.
+    pub fn total_channels(&self) -> u64 {
+        self.object_totals.channels
+    }
+
+    /// Returns the total number of queues and streams in the cluster,
+    /// or `0` if object totals are not reported.
+    pub fn total_queues(&self) -> u64 {
+        self.object_totals.queues
+    }
+
+    /// Returns the total number of consumers in the cluster,
+    /// or `0` if object totals are not reported.
+    pub fn total_consumers(&self) -> u64 {
+        self.object_totals.consumers
+    }
+
+    /// Returns the cluster-wide message publishing rate,
+    /// or `0.0` if message stats are not reported.
+    pub fn publishing_rate(&self) -> f64 {
+        self.message_stats.publishing_details.rate
+    }
+
+    /// Returns true if the management plugin collects rates (`*_details.rate` fields),
+    /// that is, it runs in the `basic` or `detailed` rates mode.
+    pub fn rates_available(&self) -> bool {
+        matches!(
+            self.rates_mode,
+            Some(RatesMode::Basic) | Some(RatesMode::Detailed)
+        )
+    }
+}
+
+/// Summarizes what a node supports, derived from the versions
+/// and product information reported by [`Overview`].
+///
+/// Use it to branch on features available in a specific RabbitMQ series
+/// instead of probing individual endpoints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+pub struct ServerCapabilities {
+    pub rabbitmq_version: String,
+    pub management_version: String,
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub rates_mode: Option<RatesMode>,
+    pub product_name: String,
+    pub product_version: String,
+    /// Is this a Tanzu RabbitMQ (commercial) distribution?
+    pub is_tanzu: bool,
+    /// `GET /api/queues/detailed` is available starting with RabbitMQ 4.0
+    pub supports_detailed_queues: bool,
+    /// `GET /api/health/checks/certificate-expiration/{within}/{unit}` is available starting with RabbitMQ 3.8.10
+    pub supports_certificate_expiration_check: bool,
+    /// `GET /api/deprecated-features` is available starting with RabbitMQ 3.13
+    pub supports_deprecated_features: bool,
+    /// Cluster and node tags are available starting with RabbitMQ 4.0
+    pub supports_cluster_tags: bool,
+}
+
+impl From<&Overview> for ServerCapabilities {
+    fn from(overview: &Overview) -> Self {
+        let version = RabbitMqVersion::parse(&overview.rabbitmq_version);
+        let at_least =
+            |major, minor, patch| version >= Some(RabbitMqVersion::new(major, minor, patch));
+
+        ServerCapabilities {
+            rabbitmq_version: overview.rabbitmq_version.clone(),
+            management_version: overview.management_version.clone(),
+            rates_mode: overview.rates_mode.clone(),
+            product_name: overview.product_name.clone(),
+            product_version: overview.product_version.clone(),
+            is_tanzu: overview.product_name.to_lowercase().contains("tanzu"),
+            supports_detailed_queues: at_least(4, 0, 0),
+            supports_certificate_expiration_check: at_least(3, 8, 10),
+            supports_deprecated_features: at_least(3, 13, 0),
+            supports_cluster_tags: at_least(4, 0, 0),
+        }
+    }
+}
+
+/// A RabbitMQ version, such as the one reported by [`Overview`] or included into
+/// exported [`DefinitionSet`]s. Pre-release and build suffixes are not retained.
+///
+/// Versions are ordered, so they can be compared against a minimum supported version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RabbitMqVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl RabbitMqVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version string such as "3.13.7", "4.0.5+2.g1a2b3c4" or "4.1.0-beta.2".
+    /// Missing minor and patch components are treated as zeroes.
+    ///
+    /// Returns `None` for empty or malformed versions.
+    pub fn parse(version: &str) -> Option<Self> {
+        let core = version.split(['-', '+']).next().unwrap_or_default();
+        if core.is_empty() {
+            return None;
+        }
+
+        let mut parts = core.split('.').map(|part| part.parse::<u32>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().transpose().ok()?.unwrap_or_default();
+        let patch = parts.next().transpose().ok()?.unwrap_or_default();
+
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for RabbitMqVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum FeatureFlagState {
@@ -1200,37 +2550,282 @@ pub struct FeatureFlag {
 #[serde(transparent)]
 pub struct FeatureFlagList(pub Vec<FeatureFlag>);
 
+/// The state of a [shovel](https://www.rabbitmq.com/docs/shovel) as reported by `GET /api/shovels`.
+///
+/// States not known to this library are represented by [`ShovelState::Unknown`].
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
-#[serde(rename_all = "snake_case")]
+#[serde(from = "String", into = "String")]
+pub enum ShovelState {
+    Starting,
+    Running,
+    Terminated,
+    Unknown(String),
+}
+
+impl From<&str> for ShovelState {
+    fn from(value: &str) -> Self {
+        match value {
+            "starting" => ShovelState::Starting,
+            "running" => ShovelState::Running,
+            "terminated" => ShovelState::Terminated,
+            other => ShovelState::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShovelState {
+    fn from(value: String) -> Self {
+        ShovelState::from(value.as_str())
+    }
+}
+
+impl From<ShovelState> for String {
+    fn from(value: ShovelState) -> Self {
+        match value {
+            ShovelState::Starting => "starting".to_owned(),
+            ShovelState::Running => "running".to_owned(),
+            ShovelState::Terminated => "terminated".to_owned(),
+            ShovelState::Unknown(state) => state,
+        }
+    }
+}
+
+impl fmt::Display for ShovelState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.clone()))
+    }
+}
+
+/// The type of a [shovel](https://www.rabbitmq.com/docs/shovel) as reported by `GET /api/shovels`.
+///
+/// Types not known to this library are represented by [`ShovelType::Unknown`].
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum ShovelType {
+    /// Declared at runtime using a runtime parameter
+    Dynamic,
+    /// Declared in the node's configuration file
+    Static,
+    Unknown(String),
+}
+
+impl From<&str> for ShovelType {
+    fn from(value: &str) -> Self {
+        match value {
+            "dynamic" => ShovelType::Dynamic,
+            "static" => ShovelType::Static,
+            other => ShovelType::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShovelType {
+    fn from(value: String) -> Self {
+        ShovelType::from(value.as_str())
+    }
+}
+
+impl From<ShovelType> for String {
+    fn from(value: ShovelType) -> Self {
+        match value {
+            ShovelType::Dynamic => "dynamic".to_owned(),
+            ShovelType::Static => "static".to_owned(),
+            ShovelType::Unknown(shovel_type) => shovel_type,
+        }
+    }
+}
+
+impl fmt::Display for ShovelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.clone()))
+    }
+}
+
+/// A [shovel](https://www.rabbitmq.com/docs/shovel) and its current state.
+#[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct Shovel {
+    pub name: String,
+    /// Static shovels do not belong to a virtual host
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub vhost: Option<String>,
+    pub node: String,
+    #[serde(rename = "type")]
+    pub shovel_type: ShovelType,
+    pub state: ShovelState,
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub src_uri: Option<String>,
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub dest_uri: Option<String>,
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub src_queue: Option<String>,
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub dest_queue: Option<String>,
+    /// Why the shovel was terminated, if it was
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub reason: Option<String>,
+}
+
+/// A [federation upstream](https://rabbitmq.com/docs/federation-reference/#upstreams),
+/// parsed from a runtime parameter of the `federation-upstream` component.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[allow(dead_code)]
+pub struct FederationUpstream {
+    #[serde(skip)]
+    pub name: String,
+    #[serde(skip)]
+    pub vhost: String,
+    /// Upstream URIs. A single URI is the most common case.
+    #[serde(rename = "uri", deserialize_with = "deserialize_one_or_many_strings")]
+    pub uris: Vec<String>,
+    pub prefetch_count: Option<u32>,
+    pub ack_mode: Option<FederationAckMode>,
+    /// In seconds
+    pub reconnect_delay: Option<u32>,
+    pub trust_user_id: Option<bool>,
+    pub max_hops: Option<u32>,
+    /// In milliseconds
+    pub expires: Option<u64>,
+    /// In milliseconds
+    pub message_ttl: Option<u64>,
+}
+
+/// Federation upstreams that were successfully parsed, along with the names
+/// of upstream parameters that could not be parsed and the parsing errors.
+pub type FederationUpstreamsWithFailures =
+    (Vec<FederationUpstream>, Vec<(String, serde_json::Error)>);
+
+impl TryFrom<RuntimeParameter> for FederationUpstream {
+    type Error = serde_json::Error;
+
+    fn try_from(param: RuntimeParameter) -> Result<Self, Self::Error> {
+        let upstream: FederationUpstream =
+            serde_json::from_value(serde_json::Value::Object(param.value.0))?;
+        Ok(FederationUpstream {
+            name: param.name,
+            vhost: param.vhost,
+            ..upstream
+        })
+    }
+}
+
+/// The status of a [federation](https://www.rabbitmq.com/docs/federation) link
+/// as reported by `GET /api/federation-links`.
+///
+/// Statuses not known to this library are represented by [`FederationLinkStatus::Unknown`].
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum FederationLinkStatus {
+    Starting,
+    Running,
+    Error,
+    Unknown(String),
+}
+
+impl From<&str> for FederationLinkStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "starting" => FederationLinkStatus::Starting,
+            "running" => FederationLinkStatus::Running,
+            "error" => FederationLinkStatus::Error,
+            other => FederationLinkStatus::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FederationLinkStatus {
+    fn from(value: String) -> Self {
+        FederationLinkStatus::from(value.as_str())
+    }
+}
+
+impl From<FederationLinkStatus> for String {
+    fn from(value: FederationLinkStatus) -> Self {
+        match value {
+            FederationLinkStatus::Starting => "starting".to_owned(),
+            FederationLinkStatus::Running => "running".to_owned(),
+            FederationLinkStatus::Error => "error".to_owned(),
+            FederationLinkStatus::Unknown(status) => status,
+        }
+    }
+}
+
+impl fmt::Display for FederationLinkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from(self.clone()))
+    }
+}
+
+/// A [federation](https://www.rabbitmq.com/docs/federation) link: a connection
+/// from a federated exchange or queue to an upstream.
+#[derive(Debug, Deserialize, Clone)]
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[allow(dead_code)]
+pub struct FederationLink {
+    pub id: String,
+    pub node: String,
+    pub vhost: String,
+    /// The name of the upstream
+    pub upstream: String,
+    /// `exchange` or `queue`
+    #[serde(rename = "type")]
+    pub link_type: String,
+    pub status: FederationLinkStatus,
+    /// The federated exchange, for exchange federation links
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub exchange: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub upstream_exchange: Option<String>,
+    /// The federated queue, for queue federation links
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub queue: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub upstream_queue: Option<String>,
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub uri: Option<String>,
+    /// Why the link failed, if it did
+    #[serde(default)]
+    #[cfg_attr(feature = "tabled", tabled(display_with = "display_option"))]
+    pub error: Option<String>,
+}
+
+/// Deserialized from (and serialized as) a plain string. Phases not known to this library
+/// are represented by [`DeprecationPhase::Unknown`], so newer RabbitMQ versions can introduce new phases
+/// without breaking deserialization.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(from = "String", into = "String")]
 pub enum DeprecationPhase {
     PermittedByDefault,
     DeniedByDefault,
     Disconnected,
     Removed,
     Undefined,
+    Unknown(String),
 }
 
 impl From<&str> for DeprecationPhase {
     fn from(value: &str) -> Self {
         match value {
             "permitted_by_default" => DeprecationPhase::PermittedByDefault,
-            "denited_by_default" => DeprecationPhase::DeniedByDefault,
+            "denied_by_default" => DeprecationPhase::DeniedByDefault,
             "disconnected" => DeprecationPhase::Disconnected,
             "removed" => DeprecationPhase::Removed,
-            _ => DeprecationPhase::Undefined,
+            "undefined" => DeprecationPhase::Undefined,
+            other => DeprecationPhase::Unknown(other.to_owned()),
         }
     }
 }
 
 impl From<String> for DeprecationPhase {
     fn from(value: String) -> Self {
-        match value.as_str() {
-            "permitted_by_default" => DeprecationPhase::PermittedByDefault,
-            "denited_by_default" => DeprecationPhase::DeniedByDefault,
-            "disconnected" => DeprecationPhase::Disconnected,
-            "removed" => DeprecationPhase::Removed,
-            _ => DeprecationPhase::Undefined,
-        }
+        DeprecationPhase::from(value.as_str())
     }
 }
 
@@ -1242,6 +2837,7 @@ impl From<DeprecationPhase> for String {
             DeprecationPhase::Disconnected => "disconnected".to_owned(),
             DeprecationPhase::Removed => "removed".to_owned(),
             DeprecationPhase::Undefined => "undefined".to_owned(),
+            DeprecationPhase::Unknown(phase) => phase,
         }
     }
 }
@@ -1254,6 +2850,7 @@ impl fmt::Display for DeprecationPhase {
             DeprecationPhase::Disconnected => writeln!(f, "disconnected")?,
             DeprecationPhase::Removed => writeln!(f, "removed")?,
             DeprecationPhase::Undefined => writeln!(f, "undefined")?,
+            DeprecationPhase::Unknown(phase) => writeln!(f, "{}", phase)?,
         }
 
         Ok(())
@@ -1280,6 +2877,10 @@ fn undefined() -> String {
     "?".to_string()
 }
 
+fn default_true() -> bool {
+    true
+}
+
 fn deserialize_map_or_seq<'de, T, D>(deserializer: D) -> Result<T, D::Error>
 where
     T: Default + serde::Deserialize<'de>,
@@ -1326,6 +2927,23 @@ where
     deserialize_map_or_seq::<MessageProperties, D>(deserializer)
 }
 
+fn deserialize_one_or_many_strings<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(s) => Ok(vec![s]),
+        OneOrMany::Many(v) => Ok(v),
+    }
+}
+
 fn deserialize_runtime_parameter_value<'de, D>(
     deserializer: D,
 ) -> Result<RuntimeParameterValue, D::Error>
@@ -0,0 +1,125 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::error::{Error, HttpClientError};
+
+/// Controls if and how HTTP API requests that failed with a transient error
+/// (e.g. a `503 Service Unavailable` response from a node that is restarting) are retried.
+///
+/// Only idempotent requests (`GET`, `PUT`, `DELETE`) are retried, unless
+/// [`RetryPolicy::retry_non_idempotent_requests`] is set.
+///
+/// Example
+/// ```rust
+/// use std::time::Duration;
+/// use rabbitmq_http_client::retries::RetryPolicy;
+///
+/// // up to 5 attempts, with a delay of 200ms, 400ms, 800ms, and so on between them
+/// let policy = RetryPolicy::new(5, Duration::from_millis(200));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one
+    pub max_attempts: u32,
+    /// The delay before the first retry. Doubles with every subsequent retry.
+    pub base_delay: Duration,
+    /// Responses with these status codes will be retried
+    pub retryable_status_codes: Vec<StatusCode>,
+    /// Should connection errors and timeouts be retried?
+    pub retry_on_connection_errors: bool,
+    /// Should `POST` requests be retried? They are not idempotent, so this is disabled by default.
+    pub retry_non_idempotent_requests: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            retryable_status_codes: vec![StatusCode::SERVICE_UNAVAILABLE],
+            retry_on_connection_errors: true,
+            retry_non_idempotent_requests: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Instantiates a [`RetryPolicy`] with the given number of attempts and base delay
+    /// that retries `503 Service Unavailable` responses and connection errors.
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            ..Self::default()
+        }
+    }
+
+    /// A [`RetryPolicy`] that never retries. This is what clients use by default.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_retryable_status_codes(mut self, codes: Vec<StatusCode>) -> Self {
+        self.retryable_status_codes = codes;
+        self
+    }
+
+    pub fn with_retry_on_connection_errors(mut self, enabled: bool) -> Self {
+        self.retry_on_connection_errors = enabled;
+        self
+    }
+
+    pub fn with_retry_non_idempotent_requests(mut self, enabled: bool) -> Self {
+        self.retry_non_idempotent_requests = enabled;
+        self
+    }
+
+    /// Returns true if the given error is considered transient by this policy.
+    pub fn is_retryable(&self, error: &HttpClientError) -> bool {
+        match error {
+            Error::ClientErrorResponse { status_code, .. }
+            | Error::ServerErrorResponse { status_code, .. } => {
+                self.retryable_status_codes.contains(status_code)
+            }
+            Error::RequestError { error, .. } => {
+                self.retry_on_connection_errors && (error.is_connect() || error.is_timeout())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if a request that failed on the given attempt (starting with 1)
+    /// should be retried.
+    pub fn should_retry(&self, error: &HttpClientError, attempt: u32, idempotent: bool) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        if !idempotent && !self.retry_non_idempotent_requests {
+            return false;
+        }
+        self.is_retryable(error)
+    }
+
+    /// Returns the delay before the retry that follows the given attempt (starting with 1).
+    pub fn delay_after(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        self.base_delay.saturating_mul(1 << exponent)
+    }
+}
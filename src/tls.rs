@@ -0,0 +1,51 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use std::fs;
+use std::path::Path;
+
+use reqwest::{Certificate, Identity};
+
+use crate::error::{Error, HttpClientError};
+
+type Result<T> = std::result::Result<T, HttpClientError>;
+
+/// Loads a PEM-encoded CA certificate (bundle) to be added to the list of trusted roots.
+pub(crate) fn load_ca_certificate(path: &Path) -> Result<Certificate> {
+    let pem = read_pem_file(path)?;
+    Certificate::from_pem(&pem).map_err(|e| Error::InvalidTlsConfiguration {
+        reason: format!("could not parse CA certificate {}: {}", path.display(), e),
+    })
+}
+
+/// Loads a PEM-encoded client certificate and its PKCS #8 private key.
+pub(crate) fn load_client_identity(certificate_path: &Path, key_path: &Path) -> Result<Identity> {
+    let certificate = read_pem_file(certificate_path)?;
+    let key = read_pem_file(key_path)?;
+    Identity::from_pkcs8_pem(&certificate, &key).map_err(|e| Error::InvalidTlsConfiguration {
+        reason: format!(
+            "could not parse client certificate {} and private key {}: {}",
+            certificate_path.display(),
+            key_path.display(),
+            e
+        ),
+    })
+}
+
+fn read_pem_file(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| Error::InvalidTlsConfiguration {
+        reason: format!("could not read {}: {}", path.display(), e),
+    })
+}
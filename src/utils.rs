@@ -11,6 +11,14 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use fancy_regex::Regex;
+use reqwest::Url;
+use serde_json::Value;
+
+use crate::commons::Scheme;
+use crate::error::{Error, HttpClientError};
 
 pub fn percentage(a: u64, b: u64) -> f64 {
     (a as f64 / b as f64) * 100.0
@@ -21,6 +29,103 @@ pub fn percentage_as_text(a: u64, b: u64) -> String {
     format!("{:.2}%", p)
 }
 
+/// Builds a query string (without the leading `?`) out of key-value pairs,
+/// percent-encoding the values.
+pub fn query_string(params: &[(&str, String)]) -> String {
+    params
+        .iter()
+        .map(|(k, v)| {
+            let encoded =
+                percent_encoding::utf8_percent_encode(v, percent_encoding::NON_ALPHANUMERIC);
+            format!("{}={}", k, encoded)
+        })
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Composes an HTTP API endpoint out of its parts and validates that it parses as a URL.
+/// An empty base path means the default one, `/api`.
+pub(crate) fn endpoint_from_parts(
+    scheme: Scheme,
+    host: &str,
+    port: u16,
+    base_path: &str,
+) -> Result<String, HttpClientError> {
+    let base_path = match base_path.trim_matches('/') {
+        "" => "api",
+        other => other,
+    };
+    // IPv6 addresses must be enclosed in brackets
+    let host = if host.contains(':') && !host.starts_with('[') {
+        format!("[{}]", host)
+    } else {
+        host.to_owned()
+    };
+    let endpoint = format!("{}://{}:{}/{}", scheme, host, port, base_path);
+
+    let invalid = |reason: String| Error::InvalidEndpoint {
+        endpoint: endpoint.clone(),
+        reason,
+    };
+    if host.is_empty() {
+        return Err(invalid("host is empty".to_owned()));
+    }
+    if host.contains(['/', '?', '#', '@']) || host.contains(char::is_whitespace) {
+        return Err(invalid(format!("host {} is malformed", host)));
+    }
+    Url::parse(&endpoint).map_err(|e| invalid(e.to_string()))?;
+
+    Ok(endpoint)
+}
+
+/// Top-level sections of a definitions document, at least one of which
+/// must be present for the document to be imported.
+const DEFINITION_SET_KEYS: [&str; 10] = [
+    "users",
+    "vhosts",
+    "permissions",
+    "topic_permissions",
+    "parameters",
+    "global_parameters",
+    "policies",
+    "queues",
+    "exchanges",
+    "bindings",
+];
+
+/// Parses a definitions document and validates that it is a JSON object
+/// with at least one definitions section.
+pub(crate) fn parse_definitions(definitions: &str) -> Result<Value, HttpClientError> {
+    let value: Value =
+        serde_json::from_str(definitions).map_err(|e| Error::InvalidDefinitions {
+            reason: format!("failed to parse definitions as JSON: {}", e),
+        })?;
+
+    match value.as_object() {
+        Some(map) if DEFINITION_SET_KEYS.iter().any(|k| map.contains_key(*k)) => Ok(value),
+        Some(_) => Err(Error::InvalidDefinitions {
+            reason: format!(
+                "definitions must contain at least one of the following keys: {}",
+                DEFINITION_SET_KEYS.join(", ")
+            ),
+        }),
+        None => Err(Error::InvalidDefinitions {
+            reason: "definitions must be a JSON object".to_owned(),
+        }),
+    }
+}
+
+/// Matches a string against a regular expression the way RabbitMQ (which uses PCRE) would,
+/// including look-around assertions and backreferences.
+pub(crate) fn regex_matches(pattern: &str, haystack: &str) -> Result<bool, HttpClientError> {
+    Regex::new(pattern)
+        .and_then(|re| re.is_match(haystack))
+        .map_err(|error| Error::InvalidRegex {
+            pattern: pattern.to_owned(),
+            error,
+        })
+}
+
 #[macro_export]
 macro_rules! path_one_part {
     ($val:expr, $part:literal) => {
@@ -0,0 +1,88 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "async")]
+
+use futures_util::TryStreamExt;
+use rabbitmq_http_client::api::Client;
+use serde_json::json;
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+fn connection(n: u16) -> serde_json::Value {
+    json!({
+        "name": format!("127.0.0.1:{} -> 127.0.0.1:5672", 50000 + n),
+        "node": "rabbit@node1",
+        "state": "running",
+        "protocol": "AMQP 0-9-1",
+        "user": "guest",
+        "connected_at": 1736964000000u64,
+        "host": "127.0.0.1",
+        "port": 5672,
+        "peer_host": "127.0.0.1",
+        "peer_port": 50000 + n,
+        "channel_max": 2047,
+        "channels": 1,
+        "client_properties": {}
+    })
+}
+
+fn page_of(ns: &[u16], page: usize) -> String {
+    let items: Vec<serde_json::Value> = ns.iter().map(|n| connection(*n)).collect();
+    json!({
+        "filtered_count": 5,
+        "item_count": items.len(),
+        "items": items,
+        "page": page,
+        "page_count": 3,
+        "page_size": 2,
+        "total_count": 5
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_connections_stream_fetches_pages_lazily() {
+    let server = MockServer::start(vec![
+        (200, page_of(&[1, 2], 1)),
+        (200, page_of(&[3, 4], 2)),
+        (200, page_of(&[5], 3)),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let mut stream = Box::pin(rc.connections_stream(2));
+    let first = stream.try_next().await.unwrap().unwrap();
+    assert_eq!(first.client_port, 50001);
+    // only the first page has been fetched so far
+    assert_eq!(server.requests().len(), 1);
+
+    let rest: Vec<_> = stream.try_collect().await.unwrap();
+    let ports: Vec<u32> = rest.iter().map(|c| c.client_port).collect();
+    assert_eq!(ports, vec![50002, 50003, 50004, 50005]);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("GET /api/connections?page=1&page_size=2 "));
+    assert!(requests[1].starts_with("GET /api/connections?page=2&page_size=2 "));
+    assert!(requests[2].starts_with("GET /api/connections?page=3&page_size=2 "));
+}
+
+#[tokio::test]
+async fn test_connections_stream_surfaces_errors() {
+    let server = MockServer::start(vec![(200, page_of(&[1, 2], 1)), (500, "{}".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result: Result<Vec<_>, _> = rc.connections_stream(2).try_collect().await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "async")]
+
+use rabbitmq_http_client::api::Client;
+use serde_json::json;
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+#[tokio::test]
+async fn test_async_export_cluster_wide_definitions_to_writer() {
+    let body = json!({
+        "rabbit_version": "4.0.5",
+        "queues": [
+            {"name": "q.1", "vhost": "/", "durable": true, "auto_delete": false, "arguments": {}}
+        ],
+        "exchanges": [],
+        "bindings": []
+    })
+    .to_string();
+    let server = MockServer::start(vec![(200, body.clone())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let mut buf: Vec<u8> = Vec::new();
+    let result = rc.export_cluster_wide_definitions_to_writer(&mut buf).await;
+    assert!(
+        result.is_ok(),
+        "export_cluster_wide_definitions_to_writer returned {:?}",
+        result
+    );
+    assert_eq!(result.unwrap(), body.len() as u64);
+    assert_eq!(String::from_utf8(buf).unwrap(), body);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/definitions "));
+}
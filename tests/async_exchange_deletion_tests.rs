@@ -0,0 +1,49 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "async")]
+
+use rabbitmq_http_client::{api::Client, error::Error, requests::ExchangeParams};
+
+mod test_helpers;
+use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+
+#[tokio::test]
+async fn test_async_delete_exchanges_with_partial_failures() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let existing = "rust.tests.async.delete_exchanges_with_partial_failures.1";
+    let missing = "rust.tests.async.delete_exchanges_with_partial_failures.missing";
+
+    let params = ExchangeParams::durable_fanout(existing, None);
+    let result1 = rc.declare_exchange(vhost, &params).await;
+    assert!(result1.is_ok(), "declare_exchange returned {:?}", result1);
+
+    let result2 = rc
+        .delete_exchanges(vhost, &[existing, missing], false)
+        .await;
+    match result2 {
+        Err(Error::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, missing);
+            assert!(matches!(failures[0].1, Error::NotFound));
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+    assert!(rc.get_exchange_info(vhost, existing).await.is_err());
+
+    // idempotent deletion ignores missing exchanges
+    let result3 = rc.delete_exchanges(vhost, &[missing], true).await;
+    assert!(result3.is_ok(), "delete_exchanges returned {:?}", result3);
+}
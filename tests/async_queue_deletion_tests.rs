@@ -0,0 +1,92 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "async")]
+
+use rabbitmq_http_client::{api::Client, error::Error, requests::QueueParams};
+
+mod test_helpers;
+use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+
+#[tokio::test]
+async fn test_async_delete_queues() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let names: Vec<String> = (1..=20)
+        .map(|n| format!("rust.tests.async.delete_queues.{}", n))
+        .collect();
+    let names: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+
+    for name in &names {
+        let params = QueueParams::new_durable_classic_queue(name, None);
+        let result = rc.declare_queue(vhost, &params).await;
+        assert!(result.is_ok(), "declare_queue returned {:?}", result);
+    }
+
+    let result1 = rc.delete_queues(vhost, &names, false).await;
+    assert!(result1.is_ok(), "delete_queues returned {:?}", result1);
+
+    for name in &names {
+        assert!(rc.get_queue_info(vhost, name).await.is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_async_delete_queues_with_partial_failures() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let existing = "rust.tests.async.delete_queues_with_partial_failures.1";
+    let missing = "rust.tests.async.delete_queues_with_partial_failures.missing";
+
+    let params = QueueParams::new_durable_classic_queue(existing, None);
+    let result1 = rc.declare_queue(vhost, &params).await;
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.delete_queues(vhost, &[existing, missing], false).await;
+    match result2 {
+        Err(Error::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, missing);
+            assert!(matches!(failures[0].1, Error::NotFound));
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+    assert!(rc.get_queue_info(vhost, existing).await.is_err());
+
+    // idempotent deletion ignores missing queues
+    let result3 = rc.delete_queues(vhost, &[missing], true).await;
+    assert!(result3.is_ok(), "delete_queues returned {:?}", result3);
+}
+
+#[tokio::test]
+async fn test_async_purge_queues() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let existing = "rust.tests.async.purge_queues.1";
+    let missing = "rust.tests.async.purge_queues.missing";
+
+    let params = QueueParams::new_durable_classic_queue(existing, None);
+    let result1 = rc.declare_queue(vhost, &params).await;
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.purge_queues(vhost, &[existing, missing]).await;
+    assert!(result2.is_ok(), "purge_queues returned {:?}", result2);
+    let report = result2.unwrap();
+    assert_eq!(report.purged, vec![existing]);
+    assert_eq!(report.not_found, vec![missing]);
+
+    rc.delete_queue(vhost, existing, false).await.unwrap();
+}
@@ -0,0 +1,58 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "async")]
+
+use rabbitmq_http_client::api::Client;
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+#[tokio::test]
+async fn test_async_vhost_scoped_listing_paths() {
+    let server = MockServer::start(vec![
+        (200, "[]".to_owned()),
+        (200, "[]".to_owned()),
+        (200, "[]".to_owned()),
+        (200, "[]".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+    let vh = "vh.scoped/1";
+
+    let result1 = rc.list_permissions_in(vh).await;
+    assert!(
+        result1.is_ok(),
+        "list_permissions_in returned {:?}",
+        result1
+    );
+    let result2 = rc.list_topic_permissions_in(vh).await;
+    assert!(
+        result2.is_ok(),
+        "list_topic_permissions_in returned {:?}",
+        result2
+    );
+    let result3 = rc.list_connections_in(vh).await;
+    assert!(
+        result3.is_ok(),
+        "list_connections_in returned {:?}",
+        result3
+    );
+    let result4 = rc.list_channels_in(vh).await;
+    assert!(result4.is_ok(), "list_channels_in returned {:?}", result4);
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/vhosts/vh%2Escoped%2F1/permissions "));
+    assert!(requests[1].starts_with("GET /api/vhosts/vh%2Escoped%2F1/topic-permissions "));
+    assert!(requests[2].starts_with("GET /api/vhosts/vh%2Escoped%2F1/connections "));
+    assert!(requests[3].starts_with("GET /api/vhosts/vh%2Escoped%2F1/channels "));
+}
@@ -14,11 +14,13 @@
 use rabbitmq_http_client::{
     blocking_api::Client,
     commons::BindingDestinationType,
+    error::Error,
     requests::{ExchangeParams, QueueParams},
+    responses::BindingInfo,
 };
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_all_bindings() {
@@ -255,3 +257,421 @@ fn test_delete_exchange_bindings() {
             && b.destination == direct
             && b.source == fanout));
 }
+
+#[test]
+fn test_delete_bindings_with_partial_failures() {
+    let bindings: Vec<BindingInfo> = serde_json::from_value(serde_json::json!([
+        {"source": "events", "vhost": "/", "destination": "orders", "destination_type": "queue",
+         "routing_key": "orders.#", "arguments": {}, "properties_key": "orders.%23"},
+        {"source": "events", "vhost": "/", "destination": "audit", "destination_type": "exchange",
+         "routing_key": "", "arguments": {}, "properties_key": "~"}
+    ]))
+    .unwrap();
+    let server = MockServer::start(vec![
+        (
+            404,
+            r#"{"error":"Object Not Found","reason":"Not Found"}"#.to_owned(),
+        ),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_bindings(&bindings, false);
+    match result {
+        Err(Error::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "events -> orders");
+            assert!(matches!(failures[0].1, Error::NotFound));
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("DELETE /api/bindings/%2F/e/events/q/orders/orders%2E%2523 "));
+    assert!(requests[1].starts_with("DELETE /api/bindings/%2F/e/events/e/audit/%7E "));
+}
+
+#[test]
+fn test_binding_destination_kinds() {
+    let bindings: Vec<BindingInfo> =
+        serde_json::from_str(include_str!("fixtures/bindings.json")).unwrap();
+    assert_eq!(bindings.len(), 3);
+
+    let default_exchange_binding = &bindings[0];
+    assert!(default_exchange_binding.is_default_exchange_binding());
+    assert!(default_exchange_binding.is_queue_binding());
+    assert_eq!(
+        default_exchange_binding.destination_type,
+        BindingDestinationType::Queue
+    );
+
+    let queue_binding = &bindings[1];
+    assert!(!queue_binding.is_default_exchange_binding());
+    assert!(queue_binding.is_queue_binding());
+    assert!(!queue_binding.is_exchange_to_exchange_binding());
+
+    let e2e_binding = &bindings[2];
+    assert!(!e2e_binding.is_default_exchange_binding());
+    assert!(!e2e_binding.is_queue_binding());
+    assert!(e2e_binding.is_exchange_to_exchange_binding());
+    assert_eq!(
+        e2e_binding.destination_type,
+        BindingDestinationType::Exchange
+    );
+}
+
+#[test]
+fn test_get_binding_between_queue_and_exchange() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.cq.get_binding";
+    let fanout = "amq.fanout";
+
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.bind_queue(vh_name, cq, fanout, Some("rust.get_binding"), None);
+    assert!(result2.is_ok(), "bind_queue returned {:?}", result2);
+
+    let vec = rc.list_queue_bindings(vh_name, cq).unwrap();
+    let binding = vec.iter().find(|b| b.source == fanout).unwrap();
+    let pk = binding.properties_key.clone().unwrap();
+
+    let result3 = rc.get_binding_between_queue_and_exchange(vh_name, fanout, cq, &pk);
+    assert!(
+        result3.is_ok(),
+        "get_binding_between_queue_and_exchange returned {:?}",
+        result3
+    );
+    let fetched = result3.unwrap();
+    assert!(fetched.has_same_identity_as(binding));
+
+    let result4 =
+        rc.get_binding_between_queue_and_exchange(vh_name, fanout, cq, "rust.non_existent");
+    assert!(matches!(result4, Err(Error::NotFound)));
+
+    let _ = rc.delete_queue(vh_name, cq, false);
+}
+
+#[test]
+fn test_get_binding_between_exchanges() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let fanout = "amq.fanout";
+    let topic = "amq.topic";
+
+    let result1 = rc.bind_exchange(vh_name, topic, fanout, Some("rust.get_binding"), None);
+    assert!(result1.is_ok(), "bind_exchange returned {:?}", result1);
+
+    let vec = rc
+        .list_exchange_bindings_with_destination(vh_name, topic)
+        .unwrap();
+    let binding = vec.iter().find(|b| b.source == fanout).unwrap();
+    let pk = binding.properties_key.clone().unwrap();
+
+    let result2 = rc.get_binding_between_exchanges(vh_name, fanout, topic, &pk);
+    assert!(
+        result2.is_ok(),
+        "get_binding_between_exchanges returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().has_same_identity_as(binding));
+
+    let m: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
+    let _ = rc.delete_binding(
+        vh_name,
+        fanout,
+        topic,
+        BindingDestinationType::Exchange,
+        "rust.get_binding",
+        Some(m),
+    );
+}
+
+#[test]
+fn test_list_bindings_between_exchange_and_queue() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let cq = "rust.cq.list_bindings_between";
+    let fanout = "amq.fanout";
+    let direct = "amq.direct";
+
+    let result1 = rc.declare_queue(vh_name, &QueueParams::new_durable_classic_queue(cq, None));
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.bind_queue(vh_name, cq, fanout, Some("rust.a"), None);
+    assert!(result2.is_ok(), "bind_queue returned {:?}", result2);
+    let result3 = rc.bind_queue(vh_name, cq, fanout, Some("rust.b"), None);
+    assert!(result3.is_ok(), "bind_queue returned {:?}", result3);
+
+    let result4 = rc.list_bindings_between_exchange_and_queue(vh_name, fanout, cq);
+    assert!(
+        result4.is_ok(),
+        "list_bindings_between_exchange_and_queue returned {:?}",
+        result4
+    );
+    let vec = result4.unwrap();
+    assert_eq!(vec.len(), 2);
+    assert!(vec
+        .iter()
+        .all(|b| b.source == fanout && b.destination == cq));
+
+    // no bindings between these two
+    let result5 = rc.list_bindings_between_exchange_and_queue(vh_name, direct, cq);
+    assert!(
+        result5.is_ok(),
+        "list_bindings_between_exchange_and_queue returned {:?}",
+        result5
+    );
+    assert!(result5.unwrap().is_empty());
+
+    let _ = rc.delete_queue(vh_name, cq, false);
+}
+
+#[test]
+fn test_list_bindings_between_exchanges() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_name = "/";
+    let fanout = "amq.fanout";
+    let headers = "amq.headers";
+    let match_x = "amq.match";
+
+    let result1 = rc.bind_exchange(vh_name, headers, fanout, Some("rust.between"), None);
+    assert!(result1.is_ok(), "bind_exchange returned {:?}", result1);
+
+    let result2 = rc.list_bindings_between_exchanges(vh_name, fanout, headers);
+    assert!(
+        result2.is_ok(),
+        "list_bindings_between_exchanges returned {:?}",
+        result2
+    );
+    let vec = result2.unwrap();
+    assert!(vec
+        .iter()
+        .any(|b| b.source == fanout && b.destination == headers));
+
+    let result3 = rc.list_bindings_between_exchanges(vh_name, fanout, match_x);
+    assert!(
+        result3.is_ok(),
+        "list_bindings_between_exchanges returned {:?}",
+        result3
+    );
+    assert!(result3.unwrap().is_empty());
+
+    let m: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
+    let _ = rc.delete_binding(
+        vh_name,
+        fanout,
+        headers,
+        BindingDestinationType::Exchange,
+        "rust.between",
+        Some(m),
+    );
+}
+
+#[test]
+fn test_list_bindings_with_a_queue_destination() {
+    let body = r#"[
+        {"source": "", "vhost": "/", "destination": "rust.tests.cq.1", "destination_type": "queue",
+         "routing_key": "rust.tests.cq.1", "arguments": {}, "properties_key": "rust.tests.cq.1"},
+        {"source": "amq.fanout", "vhost": "/", "destination": "rust.tests.cq.1", "destination_type": "queue",
+         "routing_key": "", "arguments": {}, "properties_key": "~"}
+    ]"#;
+    let server = MockServer::start(vec![(200, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result =
+        rc.list_bindings_with_destination("/", "rust.tests.cq.1", BindingDestinationType::Queue);
+    assert!(
+        result.is_ok(),
+        "list_bindings_with_destination returned {:?}",
+        result
+    );
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 2);
+    // the implicit default exchange binding is included
+    assert!(vec.iter().any(|b| b.is_default_exchange_binding()));
+    assert!(vec.iter().any(|b| b.source == "amq.fanout"));
+
+    assert!(server.requests()[0].starts_with("GET /api/queues/%2F/rust%2Etests%2Ecq%2E1/bindings "));
+}
+
+#[test]
+fn test_list_bindings_with_an_exchange_destination() {
+    let body = r#"[
+        {"source": "amq.topic", "vhost": "/", "destination": "amq.fanout", "destination_type": "exchange",
+         "routing_key": "events.#", "arguments": {}, "properties_key": "events.%23"}
+    ]"#;
+    let server = MockServer::start(vec![(200, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result =
+        rc.list_bindings_with_destination("/", "amq.fanout", BindingDestinationType::Exchange);
+    assert!(
+        result.is_ok(),
+        "list_bindings_with_destination returned {:?}",
+        result
+    );
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert!(vec[0].is_exchange_to_exchange_binding());
+    assert_eq!(vec[0].source, "amq.topic");
+
+    assert!(server.requests()[0]
+        .starts_with("GET /api/exchanges/%2F/amq%2Efanout/bindings/destination "));
+}
+
+#[test]
+fn test_exchange_routing_graph_of_a_fanout_topology() {
+    let body = r#"[
+        {"source": "", "vhost": "vh.1", "destination": "cq.1", "destination_type": "queue",
+         "routing_key": "cq.1", "arguments": {}, "properties_key": "cq.1"},
+        {"source": "events", "vhost": "vh.1", "destination": "cq.1", "destination_type": "queue",
+         "routing_key": "", "arguments": {}, "properties_key": "~"},
+        {"source": "events", "vhost": "vh.1", "destination": "cq.2", "destination_type": "queue",
+         "routing_key": "", "arguments": {}, "properties_key": "~"},
+        {"source": "events", "vhost": "vh.1", "destination": "events.audit",
+         "destination_type": "exchange", "routing_key": "", "arguments": {"x-tag": "audit"},
+         "properties_key": "~1kFq2Q"},
+        {"source": "events.audit", "vhost": "vh.1", "destination": "cq.2",
+         "destination_type": "queue", "routing_key": "", "arguments": {}, "properties_key": "~"}
+    ]"#;
+    let server = MockServer::start(vec![(200, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.exchange_routing_graph("vh.1");
+    assert!(
+        result.is_ok(),
+        "exchange_routing_graph returned {:?}",
+        result
+    );
+    let graph = result.unwrap();
+
+    // the default exchange binding is omitted
+    assert_eq!(graph.edges.len(), 4);
+    let nodes: Vec<(&str, BindingDestinationType)> = graph
+        .nodes
+        .iter()
+        .map(|n| (n.name.as_str(), n.kind.clone()))
+        .collect();
+    assert_eq!(
+        nodes,
+        vec![
+            ("events", BindingDestinationType::Exchange),
+            ("cq.1", BindingDestinationType::Queue),
+            ("cq.2", BindingDestinationType::Queue),
+            ("events.audit", BindingDestinationType::Exchange),
+        ]
+    );
+
+    let fanned_out: Vec<&str> = graph
+        .edges_from("events")
+        .map(|e| e.destination.as_str())
+        .collect();
+    assert_eq!(fanned_out, vec!["cq.1", "cq.2", "events.audit"]);
+    let e2e = graph.edges_from("events").nth(2).unwrap();
+    assert_eq!(
+        e2e.arguments.0.get("x-tag"),
+        Some(&serde_json::json!("audit"))
+    );
+
+    assert!(server.requests()[0].starts_with("GET /api/bindings/vh%2E1 "));
+}
+
+fn queue_binding_json(source: &str, routing_key: &str, properties_key: &str) -> String {
+    format!(
+        r#"{{"source": "{}", "vhost": "/", "destination": "rust.tests.cq.1", "destination_type": "queue",
+            "routing_key": "{}", "arguments": {{}}, "properties_key": "{}"}}"#,
+        source, routing_key, properties_key
+    )
+}
+
+#[test]
+fn test_delete_binding_idempotent_without_matches() {
+    let server = MockServer::start(vec![(200, "[]".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_binding_idempotent(
+        "/",
+        "amq.fanout",
+        "rust.tests.cq.1",
+        BindingDestinationType::Queue,
+        "foo",
+        Some(serde_json::Map::new()),
+    );
+    assert!(
+        result.is_ok(),
+        "delete_binding_idempotent returned {:?}",
+        result
+    );
+    assert!(!result.unwrap());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/queues/%2F/rust%2Etests%2Ecq%2E1/bindings "));
+}
+
+#[test]
+fn test_delete_binding_idempotent_with_a_single_match() {
+    let body = format!(
+        "[{}, {}]",
+        queue_binding_json("amq.fanout", "foo", "foo"),
+        queue_binding_json("amq.fanout", "bar", "bar")
+    );
+    let server = MockServer::start(vec![(200, body), (204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_binding_idempotent(
+        "/",
+        "amq.fanout",
+        "rust.tests.cq.1",
+        BindingDestinationType::Queue,
+        "foo",
+        Some(serde_json::Map::new()),
+    );
+    assert!(
+        result.is_ok(),
+        "delete_binding_idempotent returned {:?}",
+        result
+    );
+    assert!(result.unwrap());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1]
+        .starts_with("DELETE /api/bindings/%2F/e/amq%2Efanout/q/rust%2Etests%2Ecq%2E1/foo "));
+}
+
+#[test]
+fn test_delete_binding_idempotent_with_multiple_matches() {
+    let body = format!(
+        "[{}, {}]",
+        queue_binding_json("amq.fanout", "foo", "foo"),
+        queue_binding_json("amq.fanout", "foo", "foo~1")
+    );
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_binding_idempotent(
+        "/",
+        "amq.fanout",
+        "rust.tests.cq.1",
+        BindingDestinationType::Queue,
+        "foo",
+        Some(serde_json::Map::new()),
+    );
+    match result {
+        Err(Error::MultipleMatchingBindings) => (),
+        other => panic!("expected a MultipleMatchingBindings error, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
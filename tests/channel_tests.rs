@@ -11,10 +11,10 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::{blocking_api::Client, responses::Channel};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_channels() {
@@ -34,3 +34,55 @@ fn test_list_virtual_host_channels() {
     let result1 = rc.list_channels_in(vh_name);
     assert!(result1.is_ok(), "list_channels_in returned {:?}", result1);
 }
+
+#[test]
+fn test_channel_without_prefetch_and_unacked_metrics() {
+    let json = include_str!("fixtures/channels.json");
+    let channels: Vec<Channel> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(channels.len(), 3);
+    let ch = &channels[2];
+    assert_eq!(ch.prefetch_count, 0);
+    assert_eq!(ch.global_prefetch_count, 0);
+    assert_eq!(ch.messages_unacknowledged, 0);
+    assert!(!ch.has_global_prefetch());
+    assert!(ch.message_stats.is_none());
+}
+
+#[test]
+fn test_channels_with_unacked_above() {
+    let json = include_str!("fixtures/channels.json");
+    let server = MockServer::start(vec![(200, json.to_owned()), (200, json.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.channels_with_unacked_above(1000);
+    assert!(
+        result1.is_ok(),
+        "channels_with_unacked_above returned {:?}",
+        result1
+    );
+    let vec = result1.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].messages_unacknowledged, 4500);
+
+    let result2 = rc.channels_with_unacked_above(4500);
+    assert!(result2.unwrap().is_empty());
+}
+
+#[test]
+fn test_channels_with_global_prefetch() {
+    let json = include_str!("fixtures/channels.json");
+    let server = MockServer::start(vec![(200, json.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.channels_with_global_prefetch();
+    assert!(
+        result.is_ok(),
+        "channels_with_global_prefetch returned {:?}",
+        result
+    );
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].global_prefetch_count, 250);
+    assert!(server.requests()[0].starts_with("GET /api/channels"));
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::blocking_api::{Client, ClientBuilder, DEFAULT_USER_AGENT};
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+const OVERVIEW: &str = include_str!("fixtures/overview_oss.json");
+
+#[test]
+fn test_basic_auth_credentials_produce_a_basic_authorization_header() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.overview();
+    assert!(result.is_ok(), "overview returned {:?}", result);
+    // guest:guest
+    assert_eq!(
+        server.header_values("authorization"),
+        vec![Some("Basic Z3Vlc3Q6Z3Vlc3Q=".to_owned())]
+    );
+}
+
+#[test]
+fn test_oauth2_token_produces_a_bearer_authorization_header() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned()), (204, "".to_owned())]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_oauth2_token("eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl")
+        .build();
+
+    let result1 = rc.overview();
+    assert!(result1.is_ok(), "overview returned {:?}", result1);
+    let result2 = rc.delete_queue("/", "qq.1", true);
+    assert!(result2.is_ok(), "delete_queue returned {:?}", result2);
+
+    let expected = Some("Bearer eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl".to_owned());
+    assert_eq!(
+        server.header_values("authorization"),
+        vec![expected.clone(), expected]
+    );
+}
+
+#[test]
+fn test_the_last_configured_authentication_mode_wins() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned()), (200, OVERVIEW.to_owned())]);
+
+    let rc1 = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_oauth2_token("a.token")
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .build();
+    assert!(rc1.overview().is_ok());
+    // closes the kept-alive connection, the mock server handles one connection at a time
+    drop(rc1);
+
+    let rc2 = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_oauth2_token("a.token")
+        .build();
+    assert!(rc2.overview().is_ok());
+
+    assert_eq!(
+        server.header_values("authorization"),
+        vec![
+            Some("Basic Z3Vlc3Q6Z3Vlc3Q=".to_owned()),
+            Some("Bearer a.token".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn test_default_user_agent() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    assert!(rc.overview().is_ok());
+    assert_eq!(
+        server.header_values("user-agent"),
+        vec![Some(DEFAULT_USER_AGENT.to_owned())]
+    );
+    assert!(DEFAULT_USER_AGENT.starts_with("rabbitmq-http-api-rs/"));
+}
+
+#[test]
+fn test_custom_user_agent() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned()), (204, "".to_owned())]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_user_agent("provisioning-automation/2.1")
+        .build();
+
+    assert!(rc.overview().is_ok());
+    assert!(rc.delete_queue("/", "qq.1", true).is_ok());
+
+    let expected = Some("provisioning-automation/2.1".to_owned());
+    assert_eq!(
+        server.header_values("user-agent"),
+        vec![expected.clone(), expected]
+    );
+}
+
+#[test]
+fn test_cloned_client_uses_the_same_credentials() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned()), (200, OVERVIEW.to_owned())]);
+    let rc1 = Client::new(&server.endpoint, "a-user", "s3kRe7.pa55w0rd");
+    let rc2 = rc1.clone();
+
+    assert!(rc1.overview().is_ok());
+    assert!(rc2.overview().is_ok());
+
+    let values = server.header_values("authorization");
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0], values[1]);
+}
+
+#[test]
+fn test_client_debug_output_redacts_credentials() {
+    let rc1 = Client::new("http://localhost:15672/api", "a-user", "s3kRe7.pa55w0rd");
+    let debug1 = format!("{:?}", rc1.clone());
+    assert!(debug1.contains("a-user"));
+    assert!(debug1.contains("http://localhost:15672/api"));
+    assert!(!debug1.contains("s3kRe7.pa55w0rd"));
+
+    let rc2 = ClientBuilder::new()
+        .with_oauth2_token("eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl")
+        .build();
+    let debug2 = format!("{:?}", rc2);
+    assert!(!debug2.contains("eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl"));
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{blocking_api::ClientBuilder, commons::Scheme, error::Error};
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+fn port_of(endpoint: &str) -> u16 {
+    endpoint
+        .trim_start_matches("http://127.0.0.1:")
+        .trim_end_matches("/api")
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn test_with_endpoint_parts() {
+    let server = MockServer::start(vec![(200, "[]".to_owned())]);
+    let port = port_of(&server.endpoint);
+
+    let result = ClientBuilder::new()
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_endpoint_parts(Scheme::Http, "127.0.0.1", port, "/api/");
+    let rc = match result {
+        Ok(builder) => builder.build(),
+        Err(e) => panic!("with_endpoint_parts returned {:?}", e),
+    };
+
+    let vhosts = rc.list_vhosts();
+    assert!(vhosts.is_ok(), "list_vhosts returned {:?}", vhosts);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/vhosts "));
+}
+
+#[test]
+fn test_with_endpoint_parts_defaults_to_the_api_base_path() {
+    let server = MockServer::start(vec![(200, "[]".to_owned())]);
+    let port = port_of(&server.endpoint);
+
+    let rc = ClientBuilder::new()
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_endpoint_parts(Scheme::Http, "127.0.0.1", port, "")
+        .unwrap()
+        .build();
+    let _ = rc.list_vhosts();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/vhosts "));
+}
+
+#[test]
+fn test_with_endpoint_parts_rejects_a_malformed_host() {
+    for host in [
+        "",
+        "rabbit mq.local",
+        "rabbitmq.local/api",
+        "user@rabbitmq.local",
+    ] {
+        let result = ClientBuilder::new().with_endpoint_parts(Scheme::Https, host, 15671, "/api");
+        match result {
+            Err(Error::InvalidEndpoint { .. }) => (),
+            Err(other) => panic!("expected an InvalidEndpoint error, got {:?}", other),
+            Ok(_) => panic!("expected host {:?} to be rejected", host),
+        }
+    }
+}
+
+#[test]
+fn test_with_proxy() {
+    let proxy = MockServer::start(vec![(200, "[]".to_owned())]);
+
+    let result = ClientBuilder::new()
+        .with_endpoint("http://rabbitmq.eng.example.com:15672/api")
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_proxy(&proxy.endpoint.replace("/api", ""));
+    let rc = match result {
+        Ok(builder) => builder.build(),
+        Err(e) => panic!("with_proxy returned {:?}", e),
+    };
+
+    let vhosts = rc.list_vhosts();
+    assert!(vhosts.is_ok(), "list_vhosts returned {:?}", vhosts);
+
+    // the request goes to the proxy, with the absolute URI of the target
+    let requests = proxy.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET http://rabbitmq.eng.example.com:15672/api/vhosts "));
+}
+
+#[test]
+fn test_with_proxy_rejects_an_invalid_url() {
+    let result = ClientBuilder::new().with_proxy("http://[::1:3128");
+    match result {
+        Err(Error::InvalidProxy { url, .. }) => assert_eq!(url, "http://[::1:3128"),
+        Err(other) => panic!("expected an InvalidProxy error, got {:?}", other),
+        Ok(_) => panic!("expected the proxy URL to be rejected"),
+    }
+}
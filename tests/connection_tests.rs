@@ -11,10 +11,13 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::{
+    blocking_api::Client, commons::SupportedProtocol, error::Error, responses::Connection,
+};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
+use std::time::{Duration, UNIX_EPOCH};
 
 #[test]
 fn test_list_connections() {
@@ -78,3 +81,261 @@ fn test_list_virtual_host_stream_connections() {
         result1
     );
 }
+
+fn connection_json(client_properties: &str) -> String {
+    format!(
+        r#"{{
+            "name": "127.0.0.1:52470 -> 127.0.0.1:5672",
+            "node": "rabbit@localhost",
+            "state": "running",
+            "protocol": "AMQP 0-9-1",
+            "user": "guest",
+            "connected_at": 1733150000000,
+            "host": "127.0.0.1",
+            "port": 5672,
+            "peer_host": "127.0.0.1",
+            "peer_port": 52470,
+            "channel_max": 2047,
+            "channels": 1,
+            "client_properties": {}
+        }}"#,
+        client_properties
+    )
+}
+
+#[test]
+fn test_connection_connected_duration() {
+    let json = connection_json(r#"{"product": "pika"}"#);
+    let conn = serde_json::from_str::<Connection>(&json).unwrap();
+
+    // connected_at is 1733150000000
+    let now = UNIX_EPOCH + Duration::from_millis(1733150090500);
+    assert_eq!(
+        conn.connected_duration_at(now),
+        Some(Duration::from_millis(90500))
+    );
+    let before = UNIX_EPOCH + Duration::from_millis(1733140000000);
+    assert_eq!(conn.connected_duration_at(before), None);
+    assert!(conn.connected_duration().is_some());
+}
+
+#[test]
+fn test_connection_is_blocked() {
+    let json = connection_json(r#"{"product": "pika"}"#);
+    let mut conn = serde_json::from_str::<Connection>(&json).unwrap();
+    assert!(!conn.is_blocked());
+
+    conn.state = "blocking".to_owned();
+    assert!(!conn.is_blocked());
+
+    conn.state = "blocked".to_owned();
+    assert!(conn.is_blocked());
+}
+
+#[test]
+fn test_connection_with_empty_client_capabilities() {
+    let json = connection_json(r#"{"product": "pika", "capabilities": {}}"#);
+    let result = serde_json::from_str::<Connection>(&json);
+    assert!(result.is_ok(), "failed to deserialize: {:?}", result);
+
+    let conn = result.unwrap();
+    let caps = conn.client_properties.capabilities.unwrap();
+    assert!(!caps.basic_nack);
+    assert!(!caps.publisher_confirms);
+    assert!(!caps.authentication_failure_close);
+}
+
+#[test]
+fn test_connection_with_a_subset_of_client_capabilities() {
+    let json = connection_json(
+        r#"{"product": "pika", "capabilities": {"basic.nack": true, "publisher_confirms": true}}"#,
+    );
+    let result = serde_json::from_str::<Connection>(&json);
+    assert!(result.is_ok(), "failed to deserialize: {:?}", result);
+
+    let caps = result.unwrap().client_properties.capabilities.unwrap();
+    assert!(caps.basic_nack);
+    assert!(caps.publisher_confirms);
+    assert!(!caps.connection_blocked);
+    assert!(!caps.consumer_cancel_notify);
+}
+
+#[test]
+fn test_connection_without_client_capabilities() {
+    let json = connection_json(r#"{"product": "pika"}"#);
+    let result = serde_json::from_str::<Connection>(&json);
+    assert!(result.is_ok(), "failed to deserialize: {:?}", result);
+
+    let conn = result.unwrap();
+    assert!(conn.client_properties.capabilities.is_none());
+}
+
+#[test]
+fn test_list_connections_of_user_in_vhost() {
+    let conn1 = connection_json(r#"{"product": "pika"}"#);
+    let conn2 = connection_json(r#"{"product": "pika"}"#)
+        .replace(r#""user": "guest""#, r#""user": "tenant-a""#);
+    let server = MockServer::start(vec![(200, format!("[{}, {}]", conn1, conn2))]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_connections_of_user_in_vhost("tenant-a-vhost", "tenant-a");
+    assert!(
+        result.is_ok(),
+        "list_connections_of_user_in_vhost returned {:?}",
+        result
+    );
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].username, "tenant-a");
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/vhosts/tenant%2Da%2Dvhost/connections "));
+}
+
+#[test]
+fn test_list_connections_by_protocol() {
+    let amqp091 = connection_json(r#"{"product": "pika"}"#);
+    let amqp10 = connection_json(r#"{"product": "qpid"}"#)
+        .replace(r#""protocol": "AMQP 0-9-1""#, r#""protocol": "AMQP 1.0""#);
+    let amqp091_tls = connection_json(r#"{"product": "pika"}"#).replace(
+        r#""protocol": "AMQP 0-9-1","#,
+        r#""protocol": "AMQP 0-9-1", "ssl": true,"#,
+    );
+    let stream = connection_json(r#"{"product": "rabbitmq-stream"}"#).replace(
+        r#""protocol": "AMQP 0-9-1""#,
+        r#""protocol": "RabbitMQ Stream""#,
+    );
+    let mqtt = connection_json(r#"{"product": "mosquitto"}"#)
+        .replace(r#""protocol": "AMQP 0-9-1""#, r#""protocol": "MQTT 3.1.1""#);
+    let unknown =
+        connection_json(r#"{"product": "unknown"}"#).replace(r#""protocol": "AMQP 0-9-1","#, "");
+    let body = format!(
+        "[{}, {}, {}, {}, {}, {}]",
+        amqp091, amqp10, amqp091_tls, stream, mqtt, unknown
+    );
+    let server = MockServer::start(vec![
+        (200, body.clone()),
+        (200, body.clone()),
+        (200, body.clone()),
+        (200, body),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let amqp = rc
+        .list_connections_by_protocol(SupportedProtocol::AMQP)
+        .unwrap();
+    let protocols: Vec<&str> = amqp.iter().map(|c| c.protocol.as_str()).collect();
+    assert_eq!(protocols, vec!["AMQP 0-9-1", "AMQP 1.0"]);
+
+    let amqps = rc
+        .list_connections_by_protocol(SupportedProtocol::AMQPWithTLS)
+        .unwrap();
+    assert_eq!(amqps.len(), 1);
+    assert!(amqps[0].ssl);
+
+    let streams = rc
+        .list_connections_by_protocol(SupportedProtocol::Stream)
+        .unwrap();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].protocol, "RabbitMQ Stream");
+
+    let stomp = rc
+        .list_connections_by_protocol(SupportedProtocol::STOMP)
+        .unwrap();
+    assert!(stomp.is_empty());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].starts_with("GET /api/connections "));
+}
+
+#[test]
+fn test_close_connection_percent_encodes_the_name() {
+    let server = MockServer::start(vec![(204, "".to_owned()), (204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+    let name = "127.0.0.1:5672 -> 127.0.0.1:51000";
+
+    let result1 = rc.close_connection(name, None);
+    assert!(result1.is_ok(), "close_connection returned {:?}", result1);
+    let result2 = rc.close_connection(name, Some("closed by an operator"));
+    assert!(result2.is_ok(), "close_connection returned {:?}", result2);
+
+    let expected =
+        "DELETE /api/connections/127%2E0%2E0%2E1%3A5672%20%2D%3E%20127%2E0%2E0%2E1%3A51000 ";
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(
+        requests[0].starts_with(expected),
+        "unexpected request: {}",
+        requests[0]
+    );
+    assert!(
+        requests[1].starts_with(expected),
+        "unexpected request: {}",
+        requests[1]
+    );
+}
+
+#[test]
+fn test_close_all_connections() {
+    let conn1 = connection_json("{}");
+    let conn2 = connection_json("{}").replace("52470", "52471");
+    let server = MockServer::start(vec![
+        (200, format!("[{}, {}]", conn1, conn2)),
+        (204, "".to_owned()),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.close_all_connections(Some("maintenance"));
+    assert!(
+        result.is_ok(),
+        "close_all_connections returned {:?}",
+        result
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("GET /api/connections "));
+    assert!(requests[1].starts_with(
+        "DELETE /api/connections/127%2E0%2E0%2E1%3A52470%20%2D%3E%20127%2E0%2E0%2E1%3A5672 "
+    ));
+    assert!(requests[2].starts_with(
+        "DELETE /api/connections/127%2E0%2E0%2E1%3A52471%20%2D%3E%20127%2E0%2E0%2E1%3A5672 "
+    ));
+    assert_eq!(
+        server.header_values("x-reason"),
+        vec![
+            None,
+            Some("maintenance".to_owned()),
+            Some("maintenance".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn test_close_all_connections_in_with_a_failure() {
+    let conn1 = connection_json("{}");
+    let conn2 = connection_json("{}").replace("52470", "52471");
+    let server = MockServer::start(vec![
+        (200, format!("[{}, {}]", conn1, conn2)),
+        (500, "".to_owned()),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.close_all_connections_in("vh.1", None);
+    match result {
+        Err(Error::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "127.0.0.1:52470 -> 127.0.0.1:5672");
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("GET /api/vhosts/vh%2E1/connections "));
+    assert!(requests[1].starts_with("DELETE /api/connections/127%2E0%2E0%2E1%3A52470"));
+    assert!(requests[2].starts_with("DELETE /api/connections/127%2E0%2E0%2E1%3A52471"));
+}
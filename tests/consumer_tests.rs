@@ -14,7 +14,8 @@
 use rabbitmq_http_client::{blocking_api::Client, requests::VirtualHostParams};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
+use serde_json::json;
 
 #[test]
 fn test_list_consumers() {
@@ -37,3 +38,54 @@ fn test_list_vhost_consumers() {
     let result2 = rc.list_consumers_in(vh_params.name);
     assert!(result2.is_ok(), "list_consumers_in returned {:?}", result2);
 }
+
+fn consumer(tag: &str, queue: &str) -> serde_json::Value {
+    json!({
+        "arguments": {},
+        "ack_required": true,
+        "active": true,
+        "activity_status": "up",
+        "channel_details": {
+            "connection_name": "127.0.0.1:52470 -> 127.0.0.1:5672",
+            "name": "127.0.0.1:52470 -> 127.0.0.1:5672 (1)",
+            "node": "rabbit@localhost",
+            "number": 1,
+            "peer_host": "127.0.0.1",
+            "peer_port": 52470,
+            "user": "guest"
+        },
+        "consumer_tag": tag,
+        "consumer_timeout": 1800000,
+        "exclusive": false,
+        "prefetch_count": 100,
+        "queue": {"name": queue, "vhost": "/"}
+    })
+}
+
+#[test]
+fn test_list_consumers_of_queue() {
+    let consumers = json!([
+        consumer("ctag.1", "orders"),
+        consumer("ctag.2", "invoices"),
+        consumer("ctag.3", "orders"),
+        consumer("ctag.4", "orders.archived"),
+    ]);
+    let server = MockServer::start(vec![(200, consumers.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_consumers_of_queue("/", "orders");
+    assert!(
+        result.is_ok(),
+        "list_consumers_of_queue returned {:?}",
+        result
+    );
+    let tags: Vec<String> = result
+        .unwrap()
+        .into_iter()
+        .map(|c| c.consumer_tag)
+        .collect();
+    assert_eq!(tags, vec!["ctag.1", "ctag.3"]);
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/consumers/%2F "));
+}
@@ -11,12 +11,16 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::{blocking_api::Client, error::Error};
 
 mod test_helpers;
-use crate::test_helpers::{await_metric_emission, endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{await_metric_emission, endpoint, MockServer, PASSWORD, USERNAME};
 use rabbitmq_http_client::commons::PolicyTarget;
 use rabbitmq_http_client::requests::{ExchangeParams, PolicyParams, QueueParams};
+use rabbitmq_http_client::responses::{
+    BindingInfo, DefinitionImportPlan, DefinitionSet, ExchangeInfo, QueueInfo, RabbitMqVersion,
+    ReconciliationReport,
+};
 use serde_json::{json, Map, Value};
 
 #[test]
@@ -143,3 +147,309 @@ fn test_import_definitions() {
         result1
     );
 }
+
+#[test]
+fn test_reconcile_against_definitions() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let defs = rc.export_definitions_as_data().unwrap();
+    let result = rc.reconcile_against_definitions(&defs);
+    assert!(
+        result.is_ok(),
+        "reconcile_against_definitions returned {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_reconciliation_report_computation() {
+    let defs: DefinitionSet =
+        serde_json::from_str(include_str!("fixtures/definitions.json")).unwrap();
+
+    let queues: Vec<QueueInfo> = serde_json::from_value(json!([
+        {"name": "orders", "vhost": "reconciliation", "type": "quorum"},
+        {"name": "audit", "vhost": "reconciliation", "type": "classic"},
+        {"name": "amq.gen-3j1Xc", "vhost": "reconciliation", "type": "classic", "exclusive": true},
+        {"name": "unrelated", "vhost": "/", "type": "classic"}
+    ]))
+    .unwrap();
+    let exchanges: Vec<ExchangeInfo> = serde_json::from_value(json!([
+        {"name": "", "vhost": "reconciliation", "type": "direct", "durable": true, "auto_delete": false, "arguments": {}},
+        {"name": "amq.topic", "vhost": "reconciliation", "type": "topic", "durable": true, "auto_delete": false, "arguments": {}},
+        {"name": "events", "vhost": "reconciliation", "type": "topic", "durable": true, "auto_delete": false, "arguments": {}},
+        {"name": "legacy", "vhost": "reconciliation", "type": "fanout", "durable": true, "auto_delete": false, "arguments": {}},
+        {"name": "unrelated", "vhost": "/", "type": "fanout", "durable": true, "auto_delete": false, "arguments": {}}
+    ]))
+    .unwrap();
+    let bindings: Vec<BindingInfo> = serde_json::from_value(json!([
+        {"source": "", "vhost": "reconciliation", "destination": "orders", "destination_type": "queue", "routing_key": "orders", "arguments": {}, "properties_key": "orders"},
+        {"source": "events", "vhost": "reconciliation", "destination": "orders", "destination_type": "queue", "routing_key": "orders.#", "arguments": {}, "properties_key": "orders.%23"},
+        {"source": "events", "vhost": "reconciliation", "destination": "audit", "destination_type": "queue", "routing_key": "#", "arguments": {}, "properties_key": "%23"}
+    ]))
+    .unwrap();
+
+    let report = ReconciliationReport::compute(&defs, queues, exchanges, bindings);
+    assert!(!report.is_in_sync());
+
+    assert_eq!(report.missing_queues.len(), 1);
+    assert_eq!(report.missing_queues[0].name, "invoices");
+    assert_eq!(report.unexpected_queues.len(), 1);
+    assert_eq!(report.unexpected_queues[0].name, "audit");
+
+    assert!(report.missing_exchanges.is_empty());
+    assert_eq!(report.unexpected_exchanges.len(), 1);
+    assert_eq!(report.unexpected_exchanges[0].name, "legacy");
+
+    assert_eq!(report.missing_bindings.len(), 1);
+    assert_eq!(report.missing_bindings[0].destination, "invoices");
+    assert_eq!(report.unexpected_bindings.len(), 1);
+    assert_eq!(report.unexpected_bindings[0].destination, "audit");
+}
+
+fn current_definitions() -> Value {
+    json!({
+        "rabbitmq_version": "4.0.5",
+        "users": [],
+        "vhosts": [],
+        "permissions": [],
+        "parameters": [],
+        "policies": [
+            {"name": "qq.limits", "vhost": "reconciliation", "pattern": "^orders", "apply-to": "quorum_queues", "priority": 1, "definition": {"max-length": 1000}}
+        ],
+        "queues": [
+            {"name": "orders", "vhost": "reconciliation", "durable": true, "auto_delete": false, "arguments": {"x-queue-type": "quorum"}},
+            {"name": "invoices", "vhost": "/", "durable": true, "auto_delete": false, "arguments": {}}
+        ],
+        "exchanges": [],
+        "bindings": []
+    })
+}
+
+fn incoming_definitions() -> DefinitionSet {
+    let mut incoming: Value =
+        serde_json::from_str(include_str!("fixtures/definitions.json")).unwrap();
+    incoming["policies"] = json!([
+        {"name": "qq.limits", "vhost": "reconciliation", "pattern": "^orders", "apply-to": "quorum_queues", "priority": 1, "definition": {"max-length": 5000}},
+        {"name": "cq.ttl", "vhost": "reconciliation", "pattern": "^tmp", "apply-to": "classic_queues", "priority": 0, "definition": {"message-ttl": 60000}}
+    ]);
+    serde_json::from_value(incoming).unwrap()
+}
+
+#[test]
+fn test_definition_import_plan_computation() {
+    let incoming = incoming_definitions();
+    let current: DefinitionSet = serde_json::from_value(current_definitions()).unwrap();
+
+    let plan = DefinitionImportPlan::compute(&incoming, &current);
+    assert!(plan.has_overwrites());
+
+    assert_eq!(plan.queues_to_overwrite.len(), 1);
+    assert_eq!(plan.queues_to_overwrite[0].name, "orders");
+    // a queue with the same name in a different virtual host is not a conflict
+    assert_eq!(plan.queues_to_create.len(), 1);
+    assert_eq!(plan.queues_to_create[0].name, "invoices");
+
+    assert!(plan.exchanges_to_overwrite.is_empty());
+    assert_eq!(plan.exchanges_to_create.len(), 1);
+    assert_eq!(plan.exchanges_to_create[0].name, "events");
+
+    assert_eq!(plan.policies_to_overwrite.len(), 1);
+    assert_eq!(plan.policies_to_overwrite[0].name, "qq.limits");
+    assert_eq!(plan.policies_to_create.len(), 1);
+    assert_eq!(plan.policies_to_create[0].name, "cq.ttl");
+}
+
+#[test]
+fn test_plan_definition_import() {
+    let server = MockServer::start(vec![(200, current_definitions().to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.plan_definition_import(&incoming_definitions());
+    assert!(
+        result.is_ok(),
+        "plan_definition_import returned {:?}",
+        result
+    );
+    let plan = result.unwrap();
+    assert_eq!(plan.queues_to_overwrite.len(), 1);
+    assert_eq!(plan.policies_to_create.len(), 1);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/definitions "));
+}
+
+#[test]
+fn test_import_cluster_wide_definitions_from_string() {
+    let server = MockServer::start(vec![(204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let defs = r#"{"queues": [{"name": "imported.qq.1", "vhost": "/", "durable": true, "auto_delete": false, "arguments": {}}]}"#;
+    let result = rc.import_cluster_wide_definitions_from_string(defs);
+    assert!(result.is_ok(), "import returned {:?}", result);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /api/definitions "));
+}
+
+#[test]
+fn test_import_vhost_definitions_from_string() {
+    let server = MockServer::start(vec![(204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.import_vhost_definitions_from_string("/", r#"{"exchanges": []}"#);
+    assert!(result.is_ok(), "import returned {:?}", result);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /api/definitions/%2F "));
+}
+
+#[test]
+fn test_import_policies_only_definitions_from_string() {
+    let server = MockServer::start(vec![(204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let defs = r#"{"policies": [{"vhost": "/", "name": "ha", "pattern": ".*", "apply-to": "queues", "definition": {}, "priority": 0}]}"#;
+    let result = rc.import_cluster_wide_definitions_from_string(defs);
+    assert!(result.is_ok(), "import returned {:?}", result);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /api/definitions "));
+}
+
+#[test]
+fn test_import_definitions_from_malformed_string() {
+    let server = MockServer::start(vec![]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.import_cluster_wide_definitions_from_string(r#"{"queues": ["#);
+    match result {
+        Err(Error::InvalidDefinitions { reason }) => {
+            assert!(reason.contains("JSON"), "unexpected reason: {}", reason)
+        }
+        other => panic!("expected an InvalidDefinitions error, got {:?}", other),
+    }
+    // nothing should be sent
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_import_definitions_from_structurally_empty_string() {
+    let server = MockServer::start(vec![]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    for defs in [r#"{}"#, r#"{"rabbit_version": "4.0.5"}"#, r#"[]"#] {
+        let result = rc.import_cluster_wide_definitions_from_string(defs);
+        assert!(
+            matches!(result, Err(Error::InvalidDefinitions { .. })),
+            "expected an InvalidDefinitions error for {}, got {:?}",
+            defs,
+            result
+        );
+    }
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_export_cluster_wide_definitions_to_writer() {
+    let body = json!({
+        "rabbit_version": "4.0.5",
+        "queues": [
+            {"name": "q.1", "vhost": "/", "durable": true, "auto_delete": false, "arguments": {}}
+        ],
+        "exchanges": [],
+        "bindings": []
+    })
+    .to_string();
+    let server = MockServer::start(vec![(200, body.clone())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let mut buf: Vec<u8> = Vec::new();
+    let result = rc.export_cluster_wide_definitions_to_writer(&mut buf);
+    assert!(
+        result.is_ok(),
+        "export_cluster_wide_definitions_to_writer returned {:?}",
+        result
+    );
+    assert_eq!(result.unwrap(), body.len() as u64);
+    assert_eq!(String::from_utf8(buf).unwrap(), body);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/definitions "));
+}
+
+#[test]
+fn test_definition_set_export_metadata() {
+    let json = json!({
+        "rabbit_version": "3.13.7",
+        "rabbitmq_version": "3.13.7",
+        "product_name": "RabbitMQ",
+        "product_version": "3.13.7",
+        "users": [],
+        "vhosts": [],
+        "permissions": [],
+        "topic_permissions": [],
+        "parameters": [],
+        "global_parameters": [{"name": "cluster_name", "value": "rabbit@sunnyside"}],
+        "policies": [],
+        "queues": [],
+        "exchanges": [],
+        "bindings": []
+    });
+    let defs: DefinitionSet = serde_json::from_value(json).unwrap();
+
+    assert_eq!(defs.server_version, "3.13.7");
+    assert_eq!(defs.rabbit_version.as_deref(), Some("3.13.7"));
+    assert_eq!(defs.product_name.as_deref(), Some("RabbitMQ"));
+    assert_eq!(defs.product_version.as_deref(), Some("3.13.7"));
+    assert_eq!(defs.source_version(), Some(RabbitMqVersion::new(3, 13, 7)));
+}
+
+#[test]
+fn test_definition_set_source_version_falls_back_to_rabbit_version() {
+    let mut json: Value = serde_json::from_str(include_str!("fixtures/definitions.json")).unwrap();
+    json.as_object_mut().unwrap().remove("rabbitmq_version");
+    json["rabbit_version"] = json!("4.1.0-beta.2");
+    let defs: DefinitionSet = serde_json::from_value(json).unwrap();
+
+    assert!(defs.product_name.is_none());
+    assert_eq!(defs.source_version(), Some(RabbitMqVersion::new(4, 1, 0)));
+    assert!(defs.source_version().unwrap() >= RabbitMqVersion::new(4, 0, 0));
+}
+
+#[test]
+fn test_definition_set_without_any_sections() {
+    let result = serde_json::from_str::<DefinitionSet>(r#"{"rabbit_version":"4.0.0"}"#);
+    assert!(result.is_ok(), "failed to deserialize: {:?}", result);
+
+    let defs = result.unwrap();
+    assert_eq!(defs.source_version(), Some(RabbitMqVersion::new(4, 0, 0)));
+    assert!(defs.users.is_empty());
+    assert!(defs.virtual_hosts.is_empty());
+    assert!(defs.permissions.is_empty());
+    assert!(defs.parameters.is_empty());
+    assert!(defs.policies.is_empty());
+    assert!(defs.queues.is_empty());
+    assert!(defs.exchanges.is_empty());
+    assert!(defs.bindings.is_empty());
+}
+
+#[test]
+fn test_rabbitmq_version_parsing() {
+    assert_eq!(
+        RabbitMqVersion::parse("4.0.5+2.g1a2b3c4"),
+        Some(RabbitMqVersion::new(4, 0, 5))
+    );
+    assert_eq!(
+        RabbitMqVersion::parse("3.13"),
+        Some(RabbitMqVersion::new(3, 13, 0))
+    );
+    assert_eq!(RabbitMqVersion::parse(""), None);
+    assert_eq!(RabbitMqVersion::parse("unknown"), None);
+    assert_eq!(RabbitMqVersion::new(3, 13, 7).to_string(), "3.13.7");
+}
@@ -11,7 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::responses::DeprecationPhase;
+use rabbitmq_http_client::responses::{DeprecatedFeatureList, DeprecationPhase};
 use rabbitmq_http_client::{blocking_api::Client, commons::QueueType, requests::QueueParams};
 
 mod test_helpers;
@@ -53,3 +53,40 @@ fn test_list_deprecated_features_in_use() {
 
     rc.delete_queue(vh, q, true).unwrap();
 }
+
+#[test]
+fn test_deprecated_feature_list_with_an_unknown_deprecation_phase() {
+    let json = r#"[
+        {
+            "name": "transient_nonexcl_queues",
+            "desc": "Transient non-exclusive queues",
+            "deprecation_phase": "denied_by_default",
+            "doc_url": "https://blog.rabbitmq.com/posts/2021/08/4.0-deprecation-announcements/",
+            "provided_by": "rabbit"
+        },
+        {
+            "name": "some_future_feature",
+            "desc": "A feature deprecated in a phase this library does not know about",
+            "deprecation_phase": "permanently_removed",
+            "doc_url": "",
+            "provided_by": "rabbit"
+        }
+    ]"#;
+
+    let result = serde_json::from_str::<DeprecatedFeatureList>(json);
+    assert!(result.is_ok(), "failed to deserialize: {:?}", result);
+
+    let list = result.unwrap();
+    assert_eq!(
+        list.0[0].deprecation_phase,
+        DeprecationPhase::DeniedByDefault
+    );
+    assert_eq!(
+        list.0[1].deprecation_phase,
+        DeprecationPhase::Unknown("permanently_removed".to_owned())
+    );
+    assert_eq!(
+        String::from(list.0[1].deprecation_phase.clone()),
+        "permanently_removed"
+    );
+}
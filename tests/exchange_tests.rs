@@ -17,9 +17,10 @@ use rabbitmq_http_client::{
 use serde_json::{json, Map, Value};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
-use rabbitmq_http_client::commons::ExchangeType;
+use rabbitmq_http_client::commons::{required_plugins_for_exchange_type, ExchangeType};
+use rabbitmq_http_client::responses::ExchangeInfoWithRates;
 
 #[test]
 fn test_declare_a_durable_fanout_exchange() {
@@ -111,6 +112,50 @@ fn test_delete_exchange() {
     assert!(matches!(result3.unwrap_err(), APIClientError::NotFound));
 }
 
+#[test]
+fn test_delete_exchanges_with_partial_failures() {
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (
+            404,
+            r#"{"error":"Object Not Found","reason":"Not Found"}"#.to_owned(),
+        ),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_exchanges("/", &["x.1", "x.missing", "x.3"], false);
+    match result {
+        Err(APIClientError::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "x.missing");
+            assert!(matches!(failures[0].1, APIClientError::NotFound));
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("DELETE /api/exchanges/%2F/x%2E1 "));
+    assert!(requests[1].starts_with("DELETE /api/exchanges/%2F/x%2Emissing "));
+    assert!(requests[2].starts_with("DELETE /api/exchanges/%2F/x%2E3 "));
+}
+
+#[test]
+fn test_delete_exchanges_idempotently() {
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (
+            404,
+            r#"{"error":"Object Not Found","reason":"Not Found"}"#.to_owned(),
+        ),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_exchanges("/", &["x.1", "x.missing"], true);
+    assert!(result.is_ok(), "delete_exchanges returned {:?}", result);
+}
+
 #[test]
 fn test_list_all_exchanges() {
     let endpoint = endpoint();
@@ -128,3 +173,272 @@ fn test_list_exchanges_in_a_virtual_host() {
     let result1 = rc.list_exchanges_in("/");
     assert!(result1.is_ok(), "list_exchanges_in returned {:?}", result1);
 }
+
+#[test]
+fn test_exchange_type_serde_round_trip() {
+    let types = vec![
+        (ExchangeType::Fanout, "fanout"),
+        (ExchangeType::Topic, "topic"),
+        (ExchangeType::Direct, "direct"),
+        (ExchangeType::Headers, "headers"),
+        (ExchangeType::ConsistentHashing, "x-consistent-hash"),
+        (
+            ExchangeType::MessageDeduplication,
+            "x-message-deduplication",
+        ),
+        (
+            ExchangeType::Plugin("x-custom-routing".to_owned()),
+            "x-custom-routing",
+        ),
+    ];
+    for (typ, s) in types {
+        let serialized = serde_json::to_value(&typ).unwrap();
+        assert_eq!(serialized, json!(s));
+
+        let deserialized: ExchangeType = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, typ);
+        assert_eq!(ExchangeType::from(s), typ);
+    }
+}
+
+#[test]
+fn test_exchange_params_serialize_plugin_type_as_a_string() {
+    let params = ExchangeParams::durable(
+        "rust.tests.deduplication.1",
+        ExchangeType::from("x-message-deduplication"),
+        None,
+    );
+    let value = serde_json::to_value(&params).unwrap();
+    assert_eq!(value["type"], json!("x-message-deduplication"));
+
+    let params = ExchangeParams::durable(
+        "rust.tests.plugin.1",
+        ExchangeType::Plugin("x-custom-routing".to_owned()),
+        None,
+    );
+    let value = serde_json::to_value(&params).unwrap();
+    assert_eq!(value["type"], json!("x-custom-routing"));
+}
+
+#[test]
+fn test_list_exchanges_with_rates() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_exchanges_with_rates();
+    assert!(
+        result.is_ok(),
+        "list_exchanges_with_rates returned {:?}",
+        result
+    );
+    let vec = result.unwrap();
+    assert!(vec.iter().any(|x| x.name == "amq.direct" && x.vhost == "/"));
+}
+
+#[test]
+fn test_exchange_info_with_rates_deserialization() {
+    let json = json!({
+        "arguments": {},
+        "auto_delete": false,
+        "durable": true,
+        "internal": false,
+        "message_stats": {
+            "publish_in": 1200,
+            "publish_in_details": {"rate": 12.5},
+            "publish_out": 1100,
+            "publish_out_details": {"rate": 11.0}
+        },
+        "name": "events.topic",
+        "type": "topic",
+        "user_who_performed_action": "guest",
+        "vhost": "/"
+    });
+    let x: ExchangeInfoWithRates = serde_json::from_value(json).unwrap();
+    let stats = x.message_stats.unwrap();
+    assert_eq!(stats.publish_in, 1200);
+    assert_eq!(stats.publish_in_details.rate, 12.5);
+    assert_eq!(stats.publish_out, 1100);
+    assert_eq!(stats.publish_out_details.rate, 11.0);
+}
+
+#[test]
+fn test_exchange_info_with_rates_deserialization_without_message_stats() {
+    let json = json!({
+        "arguments": {},
+        "auto_delete": false,
+        "durable": true,
+        "internal": false,
+        "name": "events.topic",
+        "type": "topic",
+        "user_who_performed_action": "guest",
+        "vhost": "/"
+    });
+    let x: ExchangeInfoWithRates = serde_json::from_value(json).unwrap();
+    assert_eq!(x.exchange_type, "topic");
+    assert!(x.message_stats.is_none());
+}
+
+#[test]
+fn test_required_plugins_for_exchange_type() {
+    assert_eq!(
+        required_plugins_for_exchange_type(&ExchangeType::MessageDeduplication),
+        Some("rabbitmq_message_deduplication")
+    );
+    assert_eq!(
+        required_plugins_for_exchange_type(&ExchangeType::DelayedMessage),
+        Some("rabbitmq_delayed_message_exchange")
+    );
+    assert_eq!(
+        required_plugins_for_exchange_type(&ExchangeType::ConsistentHashing),
+        Some("rabbitmq_consistent_hash_exchange")
+    );
+    assert_eq!(
+        required_plugins_for_exchange_type(&ExchangeType::Topic),
+        None
+    );
+    assert_eq!(
+        required_plugins_for_exchange_type(&ExchangeType::LocalRandom),
+        None
+    );
+}
+
+#[test]
+fn test_declare_a_deduplication_exchange_without_the_plugin() {
+    let body = json!({
+        "error": "bad_request",
+        "reason": "unknown exchange type 'x-message-deduplication'"
+    });
+    let server = MockServer::start(vec![(400, body.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = ExchangeParams::durable(
+        "rust.tests.deduplication.2",
+        ExchangeType::MessageDeduplication,
+        None,
+    );
+    match rc.declare_exchange("/", &params) {
+        Err(APIClientError::LikelyMissingPlugin {
+            exchange_type,
+            plugin,
+            source,
+        }) => {
+            assert_eq!(exchange_type, "x-message-deduplication");
+            assert_eq!(plugin, "rabbitmq_message_deduplication");
+            match *source {
+                APIClientError::ClientErrorResponse { status_code, .. } => {
+                    assert_eq!(status_code.as_u16(), 400)
+                }
+                other => panic!("expected a ClientErrorResponse source, got {:?}", other),
+            }
+        }
+        other => panic!("expected a LikelyMissingPlugin error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_declare_a_plugin_provided_exchange_type_with_an_unrelated_server_error() {
+    for (status, body) in [
+        (
+            500,
+            json!({"error": "Internal Server Error", "reason": "function_clause"}),
+        ),
+        (
+            503,
+            json!({"error": "Service Unavailable", "reason": "node is booting"}),
+        ),
+    ] {
+        let server = MockServer::start(vec![(status, body.to_string())]);
+        let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+        let params = ExchangeParams::durable(
+            "rust.tests.deduplication.3",
+            ExchangeType::MessageDeduplication,
+            None,
+        );
+        match rc.declare_exchange("/", &params) {
+            Err(APIClientError::ServerErrorResponse { status_code, .. }) => {
+                assert_eq!(status_code.as_u16(), status)
+            }
+            other => panic!("expected a ServerErrorResponse error, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_declare_a_delayed_message_exchange_without_the_plugin() {
+    let body = json!({
+        "error": "bad_request",
+        "reason": "invalid exchange type 'x-delayed-message'"
+    });
+    let server = MockServer::start(vec![(400, body.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params =
+        ExchangeParams::durable("rust.tests.delayed.1", ExchangeType::DelayedMessage, None);
+    match rc.declare_exchange("/", &params) {
+        Err(APIClientError::LikelyMissingPlugin {
+            exchange_type,
+            plugin,
+            ..
+        }) => {
+            assert_eq!(exchange_type, "x-delayed-message");
+            assert_eq!(plugin, "rabbitmq_delayed_message_exchange");
+        }
+        other => panic!("expected a LikelyMissingPlugin error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_declare_a_core_exchange_type_with_a_server_error() {
+    let server = MockServer::start(vec![(500, "{}".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = ExchangeParams::durable_topic("rust.tests.topic.500", None);
+    match rc.declare_exchange("/", &params) {
+        Err(APIClientError::ServerErrorResponse { .. }) => (),
+        other => panic!("expected a ServerErrorResponse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_list_unused_exchanges() {
+    let exchanges = json!([
+        {"name": "", "vhost": "vh.1", "type": "direct", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "amq.topic", "vhost": "vh.1", "type": "topic", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "events", "vhost": "vh.1", "type": "topic", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "events.archive", "vhost": "vh.1", "type": "fanout", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "orphaned", "vhost": "vh.1", "type": "direct", "durable": true,
+         "auto_delete": false, "arguments": {}}
+    ]);
+    // events.archive is only a destination, orphaned is not bound at all
+    let bindings = json!([
+        {"source": "", "vhost": "vh.1", "destination": "cq.1", "destination_type": "queue",
+         "routing_key": "cq.1", "arguments": {}, "properties_key": "cq.1"},
+        {"source": "events", "vhost": "vh.1", "destination": "events.archive",
+         "destination_type": "exchange", "routing_key": "#", "arguments": {},
+         "properties_key": "%23"}
+    ]);
+    let server = MockServer::start(vec![
+        (200, exchanges.to_string()),
+        (200, bindings.to_string()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_unused_exchanges("vh.1");
+    assert!(
+        result.is_ok(),
+        "list_unused_exchanges returned {:?}",
+        result
+    );
+    let names: Vec<String> = result.unwrap().into_iter().map(|x| x.name).collect();
+    assert_eq!(names, vec!["events.archive", "orphaned"]);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /api/exchanges/vh%2E1 "));
+    assert!(requests[1].starts_with("GET /api/bindings/vh%2E1 "));
+}
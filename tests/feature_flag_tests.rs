@@ -13,11 +13,12 @@
 // limitations under the License.
 use rabbitmq_http_client::{
     blocking_api::Client,
+    error::Error,
     responses::{FeatureFlagStability, FeatureFlagState},
 };
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_feature_flags() {
@@ -70,3 +71,59 @@ fn test_enable_all_stable_feature_flags() {
         .into_iter()
         .any(|ff| ff.name == ff_name && ff.state == FeatureFlagState::Enabled));
 }
+
+#[test]
+fn test_enable_feature_flags() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let ff_names = ["detailed_queues_endpoint", "quorum_queue_non_voters"];
+
+    let result = rc.enable_feature_flags(&ff_names);
+    assert!(result.is_ok(), "enable_feature_flags returned {:?}", result);
+
+    for name in ff_names {
+        let result = rc.is_feature_flag_enabled(name);
+        assert!(
+            result.is_ok(),
+            "is_feature_flag_enabled returned {:?}",
+            result
+        );
+        assert!(result.unwrap());
+    }
+}
+
+#[test]
+fn test_enable_feature_flags_with_partial_failures() {
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (
+            400,
+            r#"{"error":"bad_request","reason":"unsupported_flag"}"#.to_owned(),
+        ),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.enable_feature_flags(&["ff.1", "ff.unknown", "ff.3"]);
+    match result {
+        Err(Error::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "ff.unknown");
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn test_is_feature_flag_enabled_with_an_unknown_flag() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result = rc.is_feature_flag_enabled("a_flag_that_does_not_exist");
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "is_feature_flag_enabled returned {:?}",
+        result
+    );
+}
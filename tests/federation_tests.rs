@@ -0,0 +1,236 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    commons::FederationAckMode,
+    error::Error,
+    requests::{FederationUpstreamParams, RuntimeParameterDefinition},
+    responses::FederationLinkStatus,
+};
+use serde_json::json;
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+#[test]
+fn test_federation_upstream_params_builder_with_all_keys() {
+    let params = FederationUpstreamParams::builder("/", "upstream.1")
+        .uri("amqp://upstream.local:5672")
+        .prefetch_count(500)
+        .ack_mode(FederationAckMode::OnPublish)
+        .reconnect_delay(Duration::from_secs(5))
+        .trust_user_id(true)
+        .max_hops(2)
+        .expires(Duration::from_secs(3600))
+        .message_ttl(Duration::from_millis(60_000))
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&params.value).unwrap(),
+        json!({
+            "uri": "amqp://upstream.local:5672",
+            "prefetch-count": 500,
+            "ack-mode": "on-publish",
+            "reconnect-delay": 5,
+            "trust-user-id": true,
+            "max-hops": 2,
+            "expires": 3_600_000,
+            "message-ttl": 60_000
+        })
+    );
+}
+
+#[test]
+fn test_federation_upstream_params_builder_only_emits_set_keys() {
+    let params = FederationUpstreamParams::builder("/", "upstream.2")
+        .uri("amqp://upstream.local:5672")
+        .ack_mode(FederationAckMode::NoAck)
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&params.value).unwrap(),
+        json!({"uri": "amqp://upstream.local:5672", "ack-mode": "no-ack"})
+    );
+}
+
+#[test]
+fn test_federation_upstream_params_into_runtime_parameter_definition() {
+    let params = FederationUpstreamParams::builder("vh.1", "upstream.3")
+        .uri("amqp://upstream.local:5672")
+        .build();
+    let definition = RuntimeParameterDefinition::from(params);
+
+    assert_eq!(definition.component, "federation-upstream");
+    assert_eq!(definition.vhost, "vh.1");
+    assert_eq!(definition.name, "upstream.3");
+    assert_eq!(
+        definition.value.get("uri"),
+        Some(&json!("amqp://upstream.local:5672"))
+    );
+}
+
+#[test]
+fn test_federation_ack_mode_conversions() {
+    assert_eq!(String::from(FederationAckMode::OnConfirm), "on-confirm");
+    assert_eq!(
+        FederationAckMode::from("on-publish"),
+        FederationAckMode::OnPublish
+    );
+    assert_eq!(
+        serde_json::to_value(FederationAckMode::NoAck).unwrap(),
+        json!("no-ack")
+    );
+    assert_eq!(
+        FederationAckMode::from("on-delivery"),
+        FederationAckMode::Unknown("on-delivery".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_value::<FederationAckMode>(json!("on-delivery")).unwrap(),
+        FederationAckMode::Unknown("on-delivery".to_owned())
+    );
+    assert_eq!(
+        FederationAckMode::Unknown("on-delivery".to_owned()).to_string(),
+        "on-delivery"
+    );
+}
+
+fn federation_links() -> serde_json::Value {
+    json!([
+        {"id": "a1b2c3d4", "node": "rabbit@hostname-1", "vhost": "vh.1", "upstream": "upstream.1",
+         "type": "exchange", "status": "running", "exchange": "events", "upstream_exchange": "events",
+         "uri": "amqp://upstream.local:5672", "timestamp": "2025-01-20 10:00:00"},
+        {"id": "e5f6a7b8", "node": "rabbit@hostname-1", "vhost": "vh.1", "upstream": "upstream.2",
+         "type": "queue", "status": "error", "queue": "orders", "upstream_queue": "orders",
+         "error": "econnrefused", "timestamp": "2025-01-20 10:00:01"},
+        {"id": "c9d0e1f2", "node": "rabbit@hostname-2", "vhost": "vh.2", "upstream": "upstream.3",
+         "type": "exchange", "status": "starting", "exchange": "audit", "upstream_exchange": "audit"}
+    ])
+}
+
+#[test]
+fn test_list_federation_links() {
+    let server = MockServer::start(vec![(200, federation_links().to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_federation_links();
+    assert!(
+        result.is_ok(),
+        "list_federation_links returned {:?}",
+        result
+    );
+    let links = result.unwrap();
+    assert_eq!(links.len(), 3);
+    assert_eq!(links[0].status, FederationLinkStatus::Running);
+    assert_eq!(links[0].exchange.as_deref(), Some("events"));
+    assert_eq!(links[1].status, FederationLinkStatus::Error);
+    assert_eq!(links[1].queue.as_deref(), Some("orders"));
+    assert_eq!(links[1].error.as_deref(), Some("econnrefused"));
+    assert_eq!(links[2].status, FederationLinkStatus::Starting);
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/federation-links "));
+}
+
+#[test]
+fn test_list_federation_links_in() {
+    let links: Vec<serde_json::Value> = federation_links()
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|l| l["vhost"] == "vh.1")
+        .cloned()
+        .collect();
+    let server = MockServer::start(vec![(200, json!(links).to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_federation_links_in("vh.1");
+    assert!(
+        result.is_ok(),
+        "list_federation_links_in returned {:?}",
+        result
+    );
+    let links = result.unwrap();
+    assert_eq!(links.len(), 2);
+    assert!(links.iter().all(|l| l.vhost == "vh.1"));
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/federation-links/vh%2E1 "));
+}
+
+#[test]
+fn test_federation_link_status() {
+    let body = federation_links().to_string();
+    let server = MockServer::start(vec![(200, body.clone()), (200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.federation_link_status("vh.1", "upstream.2");
+    assert!(
+        result1.is_ok(),
+        "federation_link_status returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap(), FederationLinkStatus::Error);
+
+    let result2 = rc.federation_link_status("vh.1", "upstream.9");
+    assert!(matches!(result2, Err(Error::NotFound)));
+}
+
+fn federation_upstream_parameters() -> String {
+    json!([
+        {"name": "upstream.1", "vhost": "/", "component": "federation-upstream",
+         "value": {"uri": "amqp://upstream.local:5672", "prefetch-count": 500, "ack-mode": "on-publish"}},
+        {"name": "upstream.2", "vhost": "/", "component": "federation-upstream",
+         "value": {"uri": ["amqp://a.local", "amqp://b.local"]}},
+        {"name": "malformed", "vhost": "/", "component": "federation-upstream",
+         "value": {"prefetch-count": "a lot"}}
+    ])
+    .to_string()
+}
+
+#[test]
+fn test_list_federation_upstreams_skips_malformed_parameters() {
+    let server = MockServer::start(vec![(200, federation_upstream_parameters())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_federation_upstreams();
+    assert!(
+        result.is_ok(),
+        "list_federation_upstreams returned {:?}",
+        result
+    );
+    let upstreams = result.unwrap();
+    assert_eq!(upstreams.len(), 2);
+    assert_eq!(upstreams[0].name, "upstream.1");
+    assert_eq!(upstreams[0].vhost, "/");
+    assert_eq!(upstreams[0].uris, vec!["amqp://upstream.local:5672"]);
+    assert_eq!(upstreams[0].prefetch_count, Some(500));
+    assert_eq!(upstreams[0].ack_mode, Some(FederationAckMode::OnPublish));
+    assert_eq!(upstreams[1].uris, vec!["amqp://a.local", "amqp://b.local"]);
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/parameters/federation%2Dupstream "));
+}
+
+#[test]
+fn test_list_federation_upstreams_lenient() {
+    let server = MockServer::start(vec![(200, federation_upstream_parameters())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let (upstreams, failures) = rc.list_federation_upstreams_lenient().unwrap();
+    assert_eq!(upstreams.len(), 2);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, "malformed");
+}
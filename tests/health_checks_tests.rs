@@ -11,10 +11,15 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{blocking_api::Client, commons::SupportedProtocol};
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    commons::{SupportedProtocol, TimeUnit},
+    error::Error,
+    responses::HealthCheckFailureDetails,
+};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_health_check_cluster_wide_alarms() {
@@ -88,3 +93,207 @@ fn test_health_check_protocol_listener_fails() {
     let result2 = rc.health_check_protocol_listener(SupportedProtocol::STOMPOverWebsocketsWithTLS);
     assert!(result2.is_err());
 }
+
+#[test]
+fn test_health_check_certificate_expiration_succeeds() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    // there are no TLS-enabled listeners in the test environment
+    let result1 = rc.health_check_certificate_expiration(1, TimeUnit::Months);
+    assert!(result1.is_ok());
+}
+
+#[test]
+fn test_health_check_certificate_expiration_fails() {
+    let body = include_str!("fixtures/health_check_certificates_expiring.json");
+    let server = MockServer::start(vec![(503, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_certificate_expiration(4, TimeUnit::Weeks);
+    match result1 {
+        Err(Error::HealthCheckFailed {
+            details: HealthCheckFailureDetails::CertificatesExpiring(details),
+            ..
+        }) => {
+            assert_eq!(details.reason, "Certificates expiring");
+            assert_eq!(details.expiring_listeners.len(), 1);
+            let listener = &details.expiring_listeners[0];
+            assert_eq!(listener.node, "rabbit@node1");
+            assert_eq!(listener.protocol, "amqp/ssl");
+            assert_eq!(listener.port, 5671);
+        }
+        other => panic!("expected a failed health check, got {:?}", other),
+    }
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/health/checks/certificate-expiration/4/weeks "));
+}
+
+#[test]
+fn test_aliveness_test() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.aliveness_test("/");
+    assert!(result1.is_ok(), "aliveness_test returned {:?}", result1);
+}
+
+#[test]
+fn test_aliveness_test_encodes_the_default_virtual_host() {
+    let server = MockServer::start(vec![(200, r#"{"status":"ok"}"#.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.aliveness_test("/");
+    assert!(result1.is_ok(), "aliveness_test returned {:?}", result1);
+    assert!(server.requests()[0].starts_with("GET /api/aliveness-test/%2F "));
+}
+
+#[test]
+fn test_aliveness_test_failure() {
+    let body = r#"{"status":"failed","reason":"timeout"}"#;
+    let server = MockServer::start(vec![(503, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.aliveness_test("vh-1");
+    match result1 {
+        Err(Error::AlivenessCheckFailed {
+            vhost,
+            body: actual,
+        }) => {
+            assert_eq!(vhost, "vh-1");
+            assert_eq!(actual, body);
+        }
+        other => panic!("expected an AlivenessCheckFailed error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_health_check_is_in_service() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_is_in_service();
+    assert!(
+        result1.is_ok(),
+        "health_check_is_in_service returned {:?}",
+        result1
+    );
+}
+
+#[test]
+fn test_health_check_below_connection_limit_succeeds() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_below_connection_limit(10000);
+    assert!(
+        result1.is_ok(),
+        "health_check_below_connection_limit returned {:?}",
+        result1
+    );
+}
+
+#[test]
+fn test_health_check_below_connection_limit_fails() {
+    let body = r#"{"status":"failed","reason":"node rabbit@node1 has 120 connections, which is over the limit of 100","limit":100,"connections":120}"#;
+    let server = MockServer::start(vec![(503, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_below_connection_limit(100);
+    match result1 {
+        Err(Error::HealthCheckFailed {
+            details: HealthCheckFailureDetails::NodeConnectionLimitReached(details),
+            ..
+        }) => {
+            assert_eq!(details.limit, 100);
+            assert_eq!(details.connection_count, 120);
+            assert_eq!(details.connections_over_limit(), 20);
+        }
+        other => panic!("expected a failed health check, got {:?}", other),
+    }
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/health/checks/below-node-connection-limit/100 "));
+}
+
+#[test]
+fn test_health_check_metadata_store_initialized_succeeds() {
+    let server = MockServer::start(vec![(200, r#"{"status":"ok"}"#.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_metadata_store_initialized();
+    assert!(
+        result1.is_ok(),
+        "health_check_metadata_store_initialized returned {:?}",
+        result1
+    );
+    assert!(server.requests()[0].starts_with("GET /api/health/checks/metadata-store/initialized "));
+}
+
+#[test]
+fn test_health_check_metadata_store_initialized_fails() {
+    let body = r#"{"status":"failed","reason":"metadata store khepri has not been initialized","metadata_store":"khepri","initialized":false}"#;
+    let server = MockServer::start(vec![(503, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.health_check_metadata_store_initialized();
+    match result1 {
+        Err(Error::HealthCheckFailed {
+            details: HealthCheckFailureDetails::MetadataStoreNotInitialized(details),
+            ..
+        }) => {
+            assert_eq!(details.metadata_store, "khepri");
+            assert!(!details.initialized);
+            assert_eq!(
+                details.reason,
+                "metadata store khepri has not been initialized"
+            );
+        }
+        other => panic!("expected a failed health check, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_node_is_quorum_critical_when_it_is_not() {
+    let server = MockServer::start(vec![(200, r#"{"status":"ok"}"#.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.node_is_quorum_critical();
+    assert!(
+        result.is_ok(),
+        "node_is_quorum_critical returned {:?}",
+        result
+    );
+    let report = result.unwrap();
+    assert!(!report.is_critical);
+    assert!(report.queues.is_empty());
+
+    assert!(server.requests()[0].starts_with("GET /api/health/checks/node-is-quorum-critical "));
+}
+
+#[test]
+fn test_node_is_quorum_critical_when_it_is() {
+    let body = r#"{
+        "status": "failed",
+        "reason": "There are quorum queues that would lose their quorum if the target node is shut down",
+        "queues": [
+            {"name": "qq.1", "readable_name": "queue 'qq.1' in vhost '/'", "virtual_host": "/", "type": "quorum"}
+        ]
+    }"#;
+    let server = MockServer::start(vec![(503, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.node_is_quorum_critical();
+    assert!(
+        result.is_ok(),
+        "node_is_quorum_critical returned {:?}",
+        result
+    );
+    let report = result.unwrap();
+    assert!(report.is_critical);
+    assert_eq!(report.queues.len(), 1);
+    assert_eq!(report.queues[0].name, "qq.1");
+    assert_eq!(report.queues[0].vhost, "/");
+    assert_eq!(report.queues[0].queue_type, "quorum");
+}
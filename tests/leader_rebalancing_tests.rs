@@ -11,10 +11,13 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use std::time::Duration;
+
 use rabbitmq_http_client::blocking_api::Client;
+use serde_json::{json, Value};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_leader_rebalancing() {
@@ -28,3 +31,82 @@ fn test_leader_rebalancing() {
         result1
     );
 }
+
+#[test]
+fn test_leader_rebalancing_and_wait() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.rebalance_queue_leaders_and_wait(Duration::from_secs(30));
+    assert!(
+        result1.is_ok(),
+        "rebalance_queue_leaders_and_wait returned {:?}",
+        result1
+    );
+    assert!(result1.unwrap().stabilized);
+}
+
+fn queue_list_with_leaders(leaders: &[&str]) -> String {
+    let queue: Value = serde_json::from_str(include_str!("fixtures/queue_quorum.json")).unwrap();
+    let queues: Vec<Value> = leaders
+        .iter()
+        .enumerate()
+        .map(|(i, leader)| {
+            let mut q = queue.clone();
+            q["name"] = json!(format!("qq.{}", i));
+            q["leader"] = json!(leader);
+            q["node"] = json!(leader);
+            q
+        })
+        .collect();
+    serde_json::to_string(&queues).unwrap()
+}
+
+#[test]
+fn test_leader_rebalancing_and_wait_with_stable_placement() {
+    let before = queue_list_with_leaders(&["rabbit@node1", "rabbit@node1", "rabbit@node1"]);
+    let moving = queue_list_with_leaders(&["rabbit@node1", "rabbit@node2", "rabbit@node1"]);
+    let after = queue_list_with_leaders(&["rabbit@node1", "rabbit@node2", "rabbit@node3"]);
+    let server = MockServer::start(vec![
+        (200, before),
+        (204, "".to_owned()),
+        (200, moving),
+        (200, after.clone()),
+        (200, after),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.rebalance_queue_leaders_and_wait(Duration::from_secs(10));
+    assert!(
+        result.is_ok(),
+        "rebalance_queue_leaders_and_wait returned {:?}",
+        result
+    );
+    let rebalancing = result.unwrap();
+    assert!(rebalancing.stabilized);
+    assert_eq!(rebalancing.before.get("rabbit@node1"), Some(&3));
+    assert_eq!(rebalancing.after.get("rabbit@node1"), Some(&1));
+    assert_eq!(rebalancing.after.get("rabbit@node2"), Some(&1));
+    assert_eq!(rebalancing.after.get("rabbit@node3"), Some(&1));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 5);
+    assert!(requests[1].starts_with("POST /api/rebalance/queues"));
+}
+
+#[test]
+fn test_leader_rebalancing_and_wait_with_a_timeout() {
+    let before = queue_list_with_leaders(&["rabbit@node1", "rabbit@node1"]);
+    let server = MockServer::start(vec![(200, before), (204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.rebalance_queue_leaders_and_wait(Duration::ZERO);
+    assert!(
+        result.is_ok(),
+        "rebalance_queue_leaders_and_wait returned {:?}",
+        result
+    );
+    let rebalancing = result.unwrap();
+    assert!(!rebalancing.stabilized);
+    assert_eq!(rebalancing.before, rebalancing.after);
+}
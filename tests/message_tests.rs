@@ -13,13 +13,16 @@
 // limitations under the License.
 use rabbitmq_http_client::{
     blocking_api::Client,
-    requests::{self, QueueParams},
+    commons::DeliveryMode,
+    error::Error,
+    requests::{self, MessagePropertiesBuilder, QueueParams},
     responses::{GetMessage, MessageProperties, MessageRouted},
 };
 use serde_json::{json, Map, Value};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
+use std::time::Duration;
 
 #[test]
 fn test_publish_and_get() {
@@ -89,3 +92,192 @@ fn test_publish_and_get() {
 
     rc.delete_queue(vhost, queue, false).unwrap();
 }
+
+#[test]
+fn test_publish_expecting_route_of_a_routed_message() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.cq.publish_expecting_route";
+
+    let _ = rc.delete_queue(vhost, queue, false);
+
+    let params = QueueParams::new_durable_classic_queue(queue, None);
+    let result1 = rc.declare_queue(vhost, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    let result2 = rc.publish_message_expecting_route(
+        vhost,
+        "",
+        queue,
+        "rust test 1",
+        requests::MessageProperties::default(),
+    );
+    assert!(
+        result2.is_ok(),
+        "publish_message_expecting_route returned {:?}",
+        result2
+    );
+    assert!(result2.unwrap().was_routed());
+
+    rc.delete_queue(vhost, queue, false).unwrap();
+}
+
+#[test]
+fn test_publish_expecting_route_of_an_unroutable_message() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.cq.publish_expecting_route.does_not_exist";
+
+    let _ = rc.delete_queue(vhost, queue, false);
+
+    let result1 = rc.publish_message(
+        vhost,
+        "",
+        queue,
+        "rust test 1",
+        requests::MessageProperties::default(),
+    );
+    assert!(result1.is_ok(), "publish_message returned {:?}", result1);
+    assert!(!result1.unwrap().was_routed());
+
+    let result2 = rc.publish_message_expecting_route(
+        vhost,
+        "",
+        queue,
+        "rust test 2",
+        requests::MessageProperties::default(),
+    );
+    assert!(matches!(result2, Err(Error::MessageNotRouted)));
+}
+
+#[test]
+fn test_publish_bytes_and_get() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let vhost = "/";
+    let queue = "rust.tests.cq.publish_bytes_and_get";
+
+    let _ = rc.delete_queue(vhost, queue, false);
+
+    let params = QueueParams::new_durable_classic_queue(queue, None);
+    let result1 = rc.declare_queue(vhost, &params);
+    assert!(result1.is_ok(), "declare_queue returned {:?}", result1);
+
+    // not valid UTF-8
+    let payload: Vec<u8> = vec![0x08, 0x96, 0x01, 0xff, 0xfe, 0x00, 0xc3, 0x28];
+    let result2 = rc.publish_message_bytes(
+        vhost,
+        "",
+        queue,
+        &payload,
+        requests::MessageProperties::default(),
+    );
+    assert!(
+        result2.is_ok(),
+        "publish_message_bytes returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap(), MessageRouted { routed: true });
+
+    let result3 = rc.get_messages(vhost, queue, 1, "ack_requeue_false");
+    assert!(result3.is_ok(), "get_messages returned {:?}", result3);
+
+    let messages = result3.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].payload_encoding, "base64");
+    assert_eq!(messages[0].decoded_payload(), Some(payload));
+
+    rc.delete_queue(vhost, queue, false).unwrap();
+}
+
+#[test]
+fn test_decoded_payload_of_a_string_message() {
+    let message = GetMessage {
+        payload_bytes: 11,
+        redelivered: false,
+        exchange: "".to_owned(),
+        routing_key: "rust.tests.cq.1".to_owned(),
+        message_count: 0,
+        properties: MessageProperties::default(),
+        payload: "rust test 1".to_owned(),
+        payload_encoding: "string".to_owned(),
+    };
+    assert_eq!(message.decoded_payload(), Some(b"rust test 1".to_vec()));
+}
+
+#[test]
+fn test_decoded_payload_of_a_malformed_base64_message() {
+    let message = GetMessage {
+        payload_bytes: 3,
+        redelivered: false,
+        exchange: "".to_owned(),
+        routing_key: "rust.tests.cq.1".to_owned(),
+        message_count: 0,
+        properties: MessageProperties::default(),
+        payload: "not base64!".to_owned(),
+        payload_encoding: "base64".to_owned(),
+    };
+    assert_eq!(message.decoded_payload(), None);
+}
+
+#[test]
+fn test_message_properties_builder() {
+    let props = MessagePropertiesBuilder::new()
+        .content_type("application/json")
+        .content_encoding("utf-8")
+        .delivery_mode(DeliveryMode::Persistent)
+        .priority(5)
+        .correlation_id("c.1")
+        .reply_to("replies")
+        .expiration(Duration::from_secs(60))
+        .message_id("m.1")
+        .build();
+
+    assert_eq!(
+        Value::Object(props),
+        json!({
+            "content_type": "application/json",
+            "content_encoding": "utf-8",
+            "delivery_mode": 2,
+            "priority": 5,
+            "correlation_id": "c.1",
+            "reply_to": "replies",
+            "expiration": "60000",
+            "message_id": "m.1"
+        })
+    );
+}
+
+#[test]
+fn test_publish_a_persistent_message_with_headers() {
+    let server = MockServer::start(vec![(200, r#"{"routed": true}"#.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let props = MessagePropertiesBuilder::new()
+        .delivery_mode(DeliveryMode::Persistent)
+        .header("x-tenant", "tenant-a")
+        .header("x-attempt", 3)
+        .build();
+    let result = rc.publish_message("/", "events", "orders.created", "{}", props);
+    assert!(result.is_ok(), "publish_message returned {:?}", result);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /api/exchanges/%2F/events/publish "));
+
+    let body: Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "routing_key": "orders.created",
+            "payload": "{}",
+            "payload_encoding": "string",
+            "properties": {
+                "delivery_mode": 2,
+                "headers": {"x-tenant": "tenant-a", "x-attempt": 3}
+            }
+        })
+    );
+}
@@ -11,10 +11,10 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::{blocking_api::Client, responses::NodeEtsMemoryFootprint};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 use regex::Regex;
 
@@ -42,3 +42,62 @@ fn test_get_node_memory_footprint() {
     let code_percentage_s = footprint.breakdown.code_percentage_as_text();
     assert!(regex.is_match(&code_percentage_s));
 }
+
+#[test]
+fn test_get_node_ets_memory_footprint() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let nodes = rc.list_nodes().unwrap();
+    let name = nodes.first().unwrap().name.clone();
+    let result = rc.get_node_ets_memory_footprint(&name);
+    assert!(
+        result.is_ok(),
+        "get_node_ets_memory_footprint returned {:?}",
+        result
+    );
+
+    let breakdown = result.unwrap();
+    assert!(breakdown.grand_total() >= 1);
+    assert!(!breakdown.tables.is_empty());
+}
+
+#[test]
+fn test_node_ets_memory_footprint_deserialization() {
+    let json = include_str!("fixtures/node_ets_memory.json");
+    let footprint: NodeEtsMemoryFootprint = serde_json::from_str(json).unwrap();
+    let breakdown = footprint.breakdown;
+
+    assert_eq!(breakdown.total, 2811640);
+    assert_eq!(breakdown.tables.len(), 14);
+    assert!(!breakdown.tables.contains_key("total"));
+    assert_eq!(breakdown.tables.get("rabbit_queue"), Some(&6544));
+
+    let sorted = breakdown.sorted_by_footprint();
+    assert_eq!(sorted[0], ("ra_log_ets", 1423488));
+    assert_eq!(sorted[1], ("khepri_rabbitmq_metadata", 1341992));
+
+    let regex = Regex::new(r"\d+\.\d+%").unwrap();
+    let s = breakdown.table_percentage_as_text("ra_log_ets").unwrap();
+    assert!(regex.is_match(&s));
+    assert!(breakdown.table_percentage("ra_log_ets").unwrap() > 50.0);
+    assert!(breakdown.table_percentage("non_existent_table").is_none());
+}
+
+#[test]
+fn test_get_node_ets_memory_footprint_request_path() {
+    let server = MockServer::start(vec![(
+        200,
+        include_str!("fixtures/node_ets_memory.json").to_owned(),
+    )]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.get_node_ets_memory_footprint("rabbit@localhost");
+    assert!(
+        result.is_ok(),
+        "get_node_ets_memory_footprint returned {:?}",
+        result
+    );
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/nodes/rabbit%40localhost/memory/ets "));
+}
@@ -11,10 +11,11 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::{blocking_api::Client, requests::NodeInfoFlags};
+use serde_json::{json, Value};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_nodes() {
@@ -39,3 +40,96 @@ fn test_get_node_info() {
     assert!(node.uptime >= 1);
     assert!(node.total_erlang_processes >= 1);
 }
+
+#[test]
+fn test_node_info_flags_query_params() {
+    let cases = [
+        (NodeInfoFlags::default(), vec![]),
+        (NodeInfoFlags::default().with_memory(), vec!["memory"]),
+        (NodeInfoFlags::default().with_binary(), vec!["binary"]),
+        (NodeInfoFlags::default().with_ets(), vec!["ets"]),
+        (
+            NodeInfoFlags::default().with_memory().with_binary(),
+            vec!["memory", "binary"],
+        ),
+        (
+            NodeInfoFlags::default().with_memory().with_ets(),
+            vec!["memory", "ets"],
+        ),
+        (
+            NodeInfoFlags::default().with_binary().with_ets(),
+            vec!["binary", "ets"],
+        ),
+        (NodeInfoFlags::all(), vec!["memory", "binary", "ets"]),
+    ];
+
+    for (flags, expected) in cases {
+        let params = flags.query_params();
+        let keys: Vec<&str> = params.iter().map(|(k, _)| *k).collect();
+        assert_eq!(
+            keys, expected,
+            "unexpected query parameters for {:?}",
+            flags
+        );
+        assert!(params.iter().all(|(_, v)| v == "true"));
+    }
+}
+
+#[test]
+fn test_get_node_info_with_details_requests() {
+    let body = include_str!("fixtures/node_with_details.json");
+    let server = MockServer::start(vec![(200, body.to_owned()), (200, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let flags = NodeInfoFlags::default().with_memory().with_binary();
+    let result1 = rc.get_node_info_with_details("rabbit@sunnyside", flags);
+    assert!(
+        result1.is_ok(),
+        "get_node_info_with_details returned {:?}",
+        result1
+    );
+    let node = result1.unwrap();
+    assert_eq!(node.memory.unwrap().plugins, 4474728);
+    assert_eq!(node.binary.unwrap().get("other"), Some(&1142816));
+    assert!(node.ets_tables.is_none());
+
+    let result2 = rc.get_node_info_with_details("rabbit@sunnyside", NodeInfoFlags::default());
+    assert!(result2.is_ok());
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/nodes/rabbit%40sunnyside?memory=true&binary=true "));
+    assert!(requests[1].starts_with("GET /api/nodes/rabbit%40sunnyside "));
+}
+
+#[test]
+fn test_cluster_summary() {
+    let mut node1: Value =
+        serde_json::from_str(include_str!("fixtures/node_with_details.json")).unwrap();
+    node1["name"] = json!("rabbit@node1");
+    node1["running"] = json!(true);
+    let mut node2 = node1.clone();
+    node2["name"] = json!("rabbit@node2");
+    node2["being_drained"] = json!(true);
+    // stopped nodes are reported without most of the metrics
+    let node3 = json!({"name": "rabbit@node3", "type": "disc", "running": false});
+
+    let body = json!([node1, node2, node3]);
+    let server = MockServer::start(vec![(200, body.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.cluster_summary();
+    assert!(result.is_ok(), "cluster_summary returned {:?}", result);
+    let summary = result.unwrap();
+    assert_eq!(summary.total, 3);
+    assert_eq!(summary.running, 2);
+    assert_eq!(summary.in_maintenance, 1);
+    assert_eq!(
+        summary.names,
+        vec!["rabbit@node1", "rabbit@node2", "rabbit@node3"]
+    );
+    assert!(!summary.is_fully_operational());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/nodes "));
+}
@@ -11,10 +11,10 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::blocking_api::Client;
+use rabbitmq_http_client::{blocking_api::Client, commons::RatesMode, responses::Overview};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_overview() {
@@ -27,3 +27,182 @@ fn test_overview() {
     let ov = result1.unwrap();
     assert!(ov.object_totals.exchanges > 0);
 }
+
+#[test]
+fn test_overview_churn_rates() {
+    let ov: Overview = serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+
+    let churn = ov.churn_rates.as_ref().unwrap();
+    assert_eq!(churn.connection_created, 12);
+    assert_eq!(churn.connection_closed, 10);
+    assert_eq!(churn.connection_created_details.rate, 2.2);
+    assert_eq!(churn.connection_closed_details.rate, 1.4);
+    assert_eq!(churn.channel_created_details.rate, 0.8);
+    assert_eq!(churn.queue_declared_details.rate, 0.2);
+
+    let rate = ov.connection_churn_per_sec().unwrap();
+    assert!((rate - 3.6).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_overview_churn_rates_counters() {
+    let ov: Overview = serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+
+    let churn = ov.churn_rates.unwrap();
+    assert_eq!(churn.queue_declared, 7);
+    assert_eq!(churn.queue_created, 5);
+    assert_eq!(churn.queue_deleted, 3);
+    assert_eq!(churn.queue_created_details.rate, 0.2);
+    assert_eq!(churn.queue_deleted_details.rate, 0.0);
+}
+
+#[test]
+fn test_overview_with_partial_churn_rates() {
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+    json["churn_rates"] = serde_json::json!({
+        "connection_created": 4,
+        "connection_created_details": {"rate": 0.4},
+        "connection_closed": 1
+    });
+    let ov: Overview = serde_json::from_value(json).unwrap();
+
+    let churn = ov.churn_rates.unwrap();
+    assert_eq!(churn.connection_created, 4);
+    assert_eq!(churn.connection_closed, 1);
+    assert_eq!(churn.connection_closed_details.rate, 0.0);
+    assert_eq!(churn.queue_declared, 0);
+    assert_eq!(churn.channel_churn_per_sec(), 0.0);
+}
+
+#[test]
+fn test_overview_without_churn_rates() {
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+    json.as_object_mut().unwrap().remove("churn_rates");
+    let ov: Overview = serde_json::from_value(json).unwrap();
+
+    assert!(ov.churn_rates.is_none());
+    assert!(ov.connection_churn_per_sec().is_none());
+}
+
+#[test]
+fn test_rates_available() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.rates_available();
+    assert!(result1.is_ok(), "rates_available returned {:?}", result1);
+}
+
+#[test]
+fn test_overview_with_basic_rates_mode() {
+    let ov: Overview = serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+
+    assert_eq!(ov.rates_mode, Some(RatesMode::Basic));
+    assert!(ov.rates_available());
+}
+
+#[test]
+fn test_overview_with_detailed_rates_mode() {
+    let ov: Overview = serde_json::from_str(include_str!("fixtures/overview_tanzu.json")).unwrap();
+
+    assert_eq!(ov.rates_mode, Some(RatesMode::Detailed));
+    assert!(ov.rates_available());
+}
+
+#[test]
+fn test_overview_with_rates_mode_none() {
+    let ov: Overview =
+        serde_json::from_str(include_str!("fixtures/overview_rates_mode_none.json")).unwrap();
+
+    assert_eq!(ov.rates_mode, Some(RatesMode::None));
+    assert!(!ov.rates_available());
+}
+
+#[test]
+fn test_overview_with_an_unknown_rates_mode() {
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+    json["rates_mode"] = serde_json::json!("adaptive");
+    let ov: Overview = serde_json::from_value(json).unwrap();
+
+    assert_eq!(
+        ov.rates_mode,
+        Some(RatesMode::Unknown("adaptive".to_owned()))
+    );
+    assert!(!ov.rates_available());
+}
+
+#[test]
+fn test_overview_totals() {
+    let ov: Overview = serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+
+    assert_eq!(ov.total_queued_messages(), 3);
+    assert_eq!(ov.total_ready_messages(), 2);
+    assert_eq!(ov.total_unacknowledged_messages(), 1);
+    assert_eq!(ov.total_connections(), 2);
+    assert_eq!(ov.total_channels(), 2);
+    assert_eq!(ov.total_queues(), 3);
+    assert_eq!(ov.total_consumers(), 1);
+}
+
+#[test]
+fn test_overview_totals_without_metrics() {
+    let json = r#"{
+        "cluster_name": "rabbit@sunnyside",
+        "node": "rabbit@sunnyside",
+        "erlang_full_version": "Erlang/OTP 27 [erts-15.2]",
+        "erlang_version": "27.2",
+        "rabbitmq_version": "4.0.5",
+        "product_name": "RabbitMQ",
+        "product_version": "4.0.5",
+        "statistics_db_event_queue": 0
+    }"#;
+    let ov: Overview = serde_json::from_str(json).unwrap();
+
+    assert_eq!(ov.total_queued_messages(), 0);
+    assert_eq!(ov.total_ready_messages(), 0);
+    assert_eq!(ov.total_unacknowledged_messages(), 0);
+    assert_eq!(ov.total_connections(), 0);
+    assert_eq!(ov.total_channels(), 0);
+    assert_eq!(ov.total_queues(), 0);
+    assert_eq!(ov.total_consumers(), 0);
+    assert_eq!(ov.publishing_rate(), 0.0);
+}
+
+#[test]
+fn test_count_connections_and_channels_using_object_totals() {
+    let body = include_str!("fixtures/overview_oss.json");
+    let server = MockServer::start(vec![(200, body.to_owned()), (200, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    assert_eq!(rc.count_connections().unwrap(), 2);
+    assert_eq!(rc.count_channels().unwrap(), 2);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /api/overview "));
+    assert!(requests[1].starts_with("GET /api/overview "));
+}
+
+#[test]
+fn test_count_channels_without_object_totals() {
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/overview_oss.json")).unwrap();
+    json.as_object_mut().unwrap().remove("object_totals");
+    let server = MockServer::start(vec![
+        (200, json.to_string()),
+        (200, include_str!("fixtures/channels.json").to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.count_channels();
+    assert!(result.is_ok(), "count_channels returned {:?}", result);
+    assert_eq!(result.unwrap(), 3);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /api/overview "));
+    assert!(requests[1].starts_with("GET /api/channels "));
+}
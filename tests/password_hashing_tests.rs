@@ -0,0 +1,94 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{
+    password_hashing::{self, HashingAlgorithm},
+    requests::UserParams,
+};
+use serde_json::json;
+
+// the salt and password used in the Credentials and Passwords guide,
+// https://rabbitmq.com/docs/passwords/#computing-password-hash
+const SALT: [u8; 4] = [0x90, 0x8D, 0xC6, 0x0A];
+const PASSWORD: &str = "test12";
+
+#[test]
+fn test_sha256_password_hash() {
+    assert_eq!(
+        password_hashing::hash_password_with(HashingAlgorithm::Sha256, &SALT, PASSWORD),
+        "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR"
+    );
+    assert_eq!(
+        password_hashing::base64_encoded_salted_password_hash_sha256(&SALT, PASSWORD),
+        "kI3GCqW5JLMJa4iX1lo7X4D6XbYqlLgxIs30+P6tENUV2POR"
+    );
+}
+
+#[test]
+fn test_sha512_password_hash() {
+    assert_eq!(
+        password_hashing::hash_password_with(HashingAlgorithm::Sha512, &SALT, PASSWORD),
+        "kI3GChuNuIYf8lRbCCxZjgjKwsY19ns6+uFO0zcXRBGA/XGJPYD8OWMy7EB8TaOmAzjP2azv84GbINYwX2cDWb4DHnc="
+    );
+}
+
+#[test]
+fn test_md5_password_hash() {
+    assert_eq!(
+        password_hashing::hash_password_with(HashingAlgorithm::Md5, &SALT, PASSWORD),
+        "kI3GChNpte2FApYFXScl+0dIluk="
+    );
+}
+
+#[test]
+fn test_salted_password_hash_lengths() {
+    let salt = password_hashing::salt();
+    let cases = [
+        (HashingAlgorithm::Sha256, 32),
+        (HashingAlgorithm::Sha512, 64),
+        (HashingAlgorithm::Md5, 16),
+    ];
+    for (algorithm, digest_len) in cases {
+        let hash = password_hashing::salted_password_hash_with(algorithm, &salt, "s3kR37");
+        assert_eq!(hash.len(), salt.len() + digest_len);
+        assert_eq!(&hash[..salt.len()], &salt[..]);
+    }
+}
+
+#[test]
+fn test_user_params_serialization_with_hashing_algorithm() {
+    let params = UserParams {
+        name: "user.sha512",
+        password_hash: "hash",
+        tags: "management",
+        hashing_algorithm: Some(HashingAlgorithm::Sha512),
+    };
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "name": "user.sha512",
+            "password_hash": "hash",
+            "tags": "management",
+            "hashing_algorithm": "rabbit_password_hashing_sha512"
+        })
+    );
+
+    let params = UserParams {
+        hashing_algorithm: None,
+        ..params
+    };
+    assert!(serde_json::to_value(&params)
+        .unwrap()
+        .get("hashing_algorithm")
+        .is_none());
+}
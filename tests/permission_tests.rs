@@ -13,10 +13,10 @@
 // limitations under the License.
 use rabbitmq_http_client::requests::VirtualHostParams;
 use rabbitmq_http_client::responses;
-use rabbitmq_http_client::{blocking_api::Client, requests::Permissions};
+use rabbitmq_http_client::{blocking_api::Client, error::Error, requests::Permissions};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_permissions() {
@@ -171,3 +171,80 @@ fn test_grant_permissions() {
 
     rc.delete_vhost(vh_params.name, false).unwrap();
 }
+
+const TOPIC_PERMISSIONS: &str = r#"[
+    {"user": "guest", "vhost": "/", "exchange": "amq.topic", "write": "^events\\..*", "read": ".*"},
+    {"user": "guest", "vhost": "/", "exchange": "logs", "write": "", "read": "^logs\\..*"}
+]"#;
+
+#[test]
+fn test_list_topic_permissions() {
+    let server = MockServer::start(vec![
+        (200, TOPIC_PERMISSIONS.to_owned()),
+        (200, TOPIC_PERMISSIONS.to_owned()),
+        (200, TOPIC_PERMISSIONS.to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.list_topic_permissions();
+    assert!(
+        result1.is_ok(),
+        "list_topic_permissions returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap().len(), 2);
+
+    let result2 = rc.list_topic_permissions_in("/");
+    assert!(
+        result2.is_ok(),
+        "list_topic_permissions_in returned {:?}",
+        result2
+    );
+
+    let result3 = rc.list_topic_permissions_of("guest");
+    assert!(
+        result3.is_ok(),
+        "list_topic_permissions_of returned {:?}",
+        result3
+    );
+    let vec = result3.unwrap();
+    assert_eq!(vec[1].exchange, "logs");
+    assert_eq!(vec[1].read, "^logs\\..*");
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/topic-permissions "));
+    assert!(requests[1].starts_with("GET /api/vhosts/%2F/topic-permissions "));
+    assert!(requests[2].starts_with("GET /api/users/guest/topic-permissions "));
+}
+
+#[test]
+fn test_get_topic_permissions_of() {
+    let server = MockServer::start(vec![(200, TOPIC_PERMISSIONS.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.get_topic_permissions_of("/", "guest");
+    assert!(
+        result.is_ok(),
+        "get_topic_permissions_of returned {:?}",
+        result
+    );
+    let permission = result.unwrap();
+    assert_eq!(permission.user, "guest");
+    assert_eq!(permission.exchange, "amq.topic");
+    assert_eq!(permission.read, ".*");
+
+    assert!(server.requests()[0].starts_with("GET /api/topic-permissions/%2F/guest "));
+}
+
+#[test]
+fn test_get_topic_permissions_of_with_an_empty_list() {
+    let server = MockServer::start(vec![(200, "[]".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.get_topic_permissions_of("/", "guest");
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "get_topic_permissions_of returned {:?}",
+        result
+    );
+}
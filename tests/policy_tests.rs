@@ -11,15 +11,18 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use std::time::Duration;
+
 use rabbitmq_http_client::{
     blocking_api::Client,
     commons::PolicyTarget,
+    error::Error,
     requests::{PolicyParams, VirtualHostParams},
 };
 
 use serde_json::{json, Map, Value};
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_message_ttl_policy() {
@@ -141,3 +144,135 @@ fn test_an_operator_policy(rc: &Client<&str, &str, &str>, policy: &PolicyParams)
     let policies = rc.list_operator_policies().unwrap();
     assert!(!policies.iter().any(|p| p.name == policy.name));
 }
+
+#[test]
+fn test_policy_params_builder_for_a_queue_scoped_policy() {
+    let params = PolicyParams::builder("/", "orders.limits")
+        .pattern("^orders\\.")
+        .apply_to(PolicyTarget::Queues)
+        .priority(10)
+        .max_length(100_000)
+        .message_ttl(Duration::from_secs(3600))
+        .dead_letter_exchange("orders.dlx")
+        .federation_upstream_set("all")
+        .ha_mode("all")
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "vhost": "/",
+            "name": "orders.limits",
+            "pattern": "^orders\\.",
+            "apply-to": "queues",
+            "priority": 10,
+            "definition": {
+                "max-length": 100_000,
+                "message-ttl": 3_600_000,
+                "dead-letter-exchange": "orders.dlx",
+                "federation-upstream-set": "all",
+                "ha-mode": "all"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_policy_params_builder_defaults() {
+    let params = PolicyParams::builder("/", "defaults").build();
+
+    assert_eq!(params.pattern, ".*");
+    assert_eq!(params.apply_to, PolicyTarget::Queues);
+    assert_eq!(params.priority, 0);
+    assert_eq!(params.definition, Some(Map::new()));
+}
+
+#[test]
+fn test_effective_policy_for_queue() {
+    let policies = json!([
+        {"name": "all.queues", "vhost": "/", "pattern": ".*", "apply-to": "queues",
+         "priority": 0, "definition": {"max-length": 100000}},
+        {"name": "orders", "vhost": "/", "pattern": "^orders\\.", "apply-to": "quorum_queues",
+         "priority": 10, "definition": {"delivery-limit": 20}},
+        {"name": "orders.exchanges", "vhost": "/", "pattern": "^orders", "apply-to": "exchanges",
+         "priority": 100, "definition": {"alternate-exchange": "unroutable"}}
+    ]);
+    let server = MockServer::start(vec![
+        (200, policies.to_string()),
+        (200, policies.to_string()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    // the exchange-only policy has the highest priority but does not apply to queues
+    let result1 = rc.effective_policy_for_queue("/", "orders.pending");
+    assert!(
+        result1.is_ok(),
+        "effective_policy_for_queue returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap().unwrap().name, "orders");
+
+    let result2 = rc.effective_policy_for_queue("/", "invoices.pending");
+    assert_eq!(result2.unwrap().unwrap().name, "all.queues");
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/policies/%2F "));
+}
+
+#[test]
+fn test_effective_policy_for_queue_with_a_lookahead_pattern() {
+    let policies = json!([
+        {"name": "all.queues", "vhost": "/", "pattern": ".*", "apply-to": "queues",
+         "priority": 0, "definition": {"max-length": 100000}},
+        {"name": "non.system.queues", "vhost": "/", "pattern": "^(?!amq\\.).*", "apply-to": "queues",
+         "priority": 10, "definition": {"max-length": 1000}}
+    ]);
+    let server = MockServer::start(vec![
+        (200, policies.to_string()),
+        (200, policies.to_string()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.effective_policy_for_queue("/", "orders.pending");
+    assert!(
+        result1.is_ok(),
+        "effective_policy_for_queue returned {:?}",
+        result1
+    );
+    assert_eq!(result1.unwrap().unwrap().name, "non.system.queues");
+
+    let result2 = rc.effective_policy_for_queue("/", "amq.gen-1234");
+    assert_eq!(result2.unwrap().unwrap().name, "all.queues");
+}
+
+#[test]
+fn test_effective_policy_for_queue_with_an_invalid_pattern() {
+    let policies = json!([
+        {"name": "all.queues", "vhost": "/", "pattern": ".*", "apply-to": "queues",
+         "priority": 0, "definition": {"max-length": 100000}},
+        {"name": "orders.invalid", "vhost": "/", "pattern": "^orders(", "apply-to": "all",
+         "priority": 50, "definition": {"message-ttl": 1000}}
+    ]);
+    let server = MockServer::start(vec![(200, policies.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    // a policy that cannot be evaluated is not silently skipped
+    let result = rc.effective_policy_for_queue("/", "orders.pending");
+    match result {
+        Err(Error::InvalidRegex { pattern, .. }) => assert_eq!(pattern, "^orders("),
+        other => panic!("expected an InvalidRegex error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_effective_policy_for_queue_without_matches() {
+    let policies = json!([
+        {"name": "orders", "vhost": "/", "pattern": "^orders", "apply-to": "queues",
+         "priority": 0, "definition": {"max-length": 100000}}
+    ]);
+    let server = MockServer::start(vec![(200, policies.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.effective_policy_for_queue("/", "invoices.pending");
+    assert!(result.unwrap().is_none());
+}
@@ -0,0 +1,277 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    commons::QueueType,
+    error::Error,
+    requests::{QueueFilter, QueuePageFilter},
+};
+use serde_json::json;
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+fn queue_info(name: &str, queue_type: &str) -> serde_json::Value {
+    json!({
+        "name": name,
+        "vhost": "/",
+        "type": queue_type,
+        "durable": true,
+        "auto_delete": false,
+        "exclusive": false,
+        "arguments": {"x-queue-type": queue_type}
+    })
+}
+
+fn page_of(items: Vec<serde_json::Value>, page: usize, page_count: usize) -> String {
+    json!({
+        "filtered_count": items.len(),
+        "item_count": items.len(),
+        "items": items,
+        "page": page,
+        "page_count": page_count,
+        "page_size": 500,
+        "total_count": 10
+    })
+    .to_string()
+}
+
+#[test]
+fn test_list_queues_filtered_passes_name_regex_as_query_parameters() {
+    let body = page_of(
+        vec![
+            queue_info("rust.tests.cq.1", "classic"),
+            queue_info("rust.tests.qq.1", "quorum"),
+        ],
+        1,
+        1,
+    );
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let filter = QueueFilter {
+        vhost: Some("/".to_owned()),
+        name_regex: Some("^rust.tests".to_owned()),
+        queue_type: Some(QueueType::Quorum),
+    };
+    let result = rc.list_queues_filtered(&filter);
+    assert!(result.is_ok(), "list_queues_filtered returned {:?}", result);
+
+    // the queue type filter is applied client-side
+    let queues = result.unwrap();
+    assert_eq!(queues.len(), 1);
+    assert_eq!(queues[0].name, "rust.tests.qq.1");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert!(request.starts_with("GET /api/queues/%2F?"), "{}", request);
+    assert!(request.contains("page=1&page_size=500"), "{}", request);
+    assert!(request.contains("name=%5Erust%2Etests"), "{}", request);
+    assert!(request.contains("use_regex=true"), "{}", request);
+}
+
+#[test]
+fn test_list_queues_filtered_fetches_all_pages() {
+    let server = MockServer::start(vec![
+        (
+            200,
+            page_of(vec![queue_info("rust.tests.cq.1", "classic")], 1, 2),
+        ),
+        (
+            200,
+            page_of(vec![queue_info("rust.tests.cq.2", "classic")], 2, 2),
+        ),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_queues_filtered(&QueueFilter::default());
+    assert!(result.is_ok(), "list_queues_filtered returned {:?}", result);
+    assert_eq!(result.unwrap().len(), 2);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /api/queues?page=1&page_size=500 "));
+    assert!(requests[1].starts_with("GET /api/queues?page=2&page_size=500 "));
+}
+
+#[test]
+fn test_list_queues_filtered_rejects_invalid_regex() {
+    let server = MockServer::start(vec![]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let filter = QueueFilter {
+        name_regex: Some("rust.tests.(".to_owned()),
+        ..QueueFilter::default()
+    };
+    let result = rc.list_queues_filtered(&filter);
+    assert!(matches!(result, Err(Error::InvalidRegex { .. })));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_list_queues_paged() {
+    let body = page_of(vec![queue_info("rust.tests.cq.3", "classic")], 3, 10);
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_queues_paged(3, 1, None);
+    assert!(result.is_ok(), "list_queues_paged returned {:?}", result);
+    let page = result.unwrap();
+    assert_eq!(page.page, 3);
+    assert_eq!(page.page_count, 10);
+    assert_eq!(page.item_count, 1);
+    assert_eq!(page.total_count, 10);
+    assert_eq!(page.items[0].name, "rust.tests.cq.3");
+    assert!(!page.is_last());
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("GET /api/queues?page=3&page_size=1 "));
+}
+
+#[test]
+fn test_list_queues_in_paged_with_a_name_filter() {
+    let body = page_of(vec![queue_info("rust.tests.cq.1", "classic")], 1, 1);
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let filter = QueuePageFilter::name_containing("cq");
+    let result = rc.list_queues_in_paged("/", 1, 100, Some(&filter));
+    assert!(result.is_ok(), "list_queues_in_paged returned {:?}", result);
+    assert!(result.unwrap().is_last());
+
+    let request = &server.requests()[0];
+    assert!(
+        request.starts_with("GET /api/queues/%2F?page=1&page_size=100&name=cq&use_regex=false "),
+        "{}",
+        request
+    );
+}
+
+#[test]
+fn test_list_queues_paged_rejects_invalid_regex() {
+    let server = MockServer::start(vec![]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let filter = QueuePageFilter::name_matching("rust.tests.(");
+    let result = rc.list_queues_paged(1, 100, Some(&filter));
+    assert!(matches!(result, Err(Error::InvalidRegex { .. })));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_list_queues_paged_with_a_lookahead_regex() {
+    let body = page_of(vec![queue_info("rust.tests.cq.1", "classic")], 1, 1);
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    // look-around assertions are supported by the server (PCRE)
+    let filter = QueuePageFilter::name_matching(r"^(?!amq\.).*");
+    let result = rc.list_queues_paged(1, 100, Some(&filter));
+    assert!(result.is_ok(), "list_queues_paged returned {:?}", result);
+
+    let request = &server.requests()[0];
+    assert!(
+        request.starts_with(
+            "GET /api/queues?page=1&page_size=100&name=%5E%28%3F%21amq%5C%2E%29%2E%2A&use_regex=true "
+        ),
+        "{}",
+        request
+    );
+}
+
+#[test]
+fn test_list_queues_matching_in_a_virtual_host() {
+    let body = json!([
+        queue_info("rust.tests.cq.1", "classic"),
+        queue_info("rust.tests.qq.1", "quorum"),
+        queue_info("rust.tests.qq.2", "quorum"),
+    ])
+    .to_string();
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_queues_matching(Some("/"), |q| q.queue_type == "quorum");
+    assert!(result.is_ok(), "list_queues_matching returned {:?}", result);
+    let names: Vec<String> = result.unwrap().into_iter().map(|q| q.name).collect();
+    assert_eq!(names, vec!["rust.tests.qq.1", "rust.tests.qq.2"]);
+
+    assert!(server.requests()[0].starts_with("GET /api/queues/%2F "));
+}
+
+#[test]
+fn test_list_queues_matching_across_the_cluster() {
+    let body = json!([
+        queue_info("rust.tests.cq.1", "classic"),
+        queue_info("rust.tests.qq.1", "quorum"),
+    ])
+    .to_string();
+    let server = MockServer::start(vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_queues_matching(None, |q| q.queue_type == "stream");
+    assert!(result.is_ok(), "list_queues_matching returned {:?}", result);
+    assert!(result.unwrap().is_empty());
+
+    assert!(server.requests()[0].starts_with("GET /api/queues "));
+}
+
+fn queue_with_flags(name: &str, exclusive: bool, auto_delete: bool) -> serde_json::Value {
+    let mut q = queue_info(name, "classic");
+    q["exclusive"] = json!(exclusive);
+    q["auto_delete"] = json!(auto_delete);
+    q
+}
+
+fn queues_with_mixed_flags() -> String {
+    json!([
+        queue_with_flags("durable.1", false, false),
+        queue_with_flags("exclusive.1", true, false),
+        queue_with_flags("exclusive.auto_delete.1", true, true),
+        queue_with_flags("auto_delete.1", false, true),
+    ])
+    .to_string()
+}
+
+#[test]
+fn test_list_exclusive_queues() {
+    let server = MockServer::start(vec![(200, queues_with_mixed_flags())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_exclusive_queues("/");
+    assert!(
+        result.is_ok(),
+        "list_exclusive_queues returned {:?}",
+        result
+    );
+    let names: Vec<String> = result.unwrap().into_iter().map(|q| q.name).collect();
+    assert_eq!(names, vec!["exclusive.1", "exclusive.auto_delete.1"]);
+
+    assert!(server.requests()[0].starts_with("GET /api/queues/%2F "));
+}
+
+#[test]
+fn test_list_auto_delete_queues() {
+    let server = MockServer::start(vec![(200, queues_with_mixed_flags())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_auto_delete_queues("/");
+    assert!(
+        result.is_ok(),
+        "list_auto_delete_queues returned {:?}",
+        result
+    );
+    let names: Vec<String> = result.unwrap().into_iter().map(|q| q.name).collect();
+    assert_eq!(names, vec!["exclusive.auto_delete.1", "auto_delete.1"]);
+}
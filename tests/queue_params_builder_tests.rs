@@ -0,0 +1,132 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use rabbitmq_http_client::{
+    commons::{ByteSize, OverflowBehavior, QueueType},
+    requests::QueueParams,
+    responses::PolicyDefinition,
+};
+use serde_json::{json, Value};
+
+#[test]
+fn test_queue_params_builder_defaults() {
+    let params = QueueParams::builder("rust.tests.builder.cq").build();
+
+    assert_eq!(params.name, "rust.tests.builder.cq");
+    assert!(params.durable);
+    assert!(!params.auto_delete);
+    assert!(!params.exclusive);
+    assert_eq!(
+        Value::Object(params.arguments.unwrap()),
+        json!({"x-queue-type": "classic"})
+    );
+}
+
+#[test]
+fn test_queue_params_builder_with_common_arguments() {
+    let params = QueueParams::builder("rust.tests.builder.qq")
+        .queue_type(QueueType::Quorum)
+        .max_length(10_000)
+        .max_length_bytes(ByteSize::mib(64))
+        .message_ttl(Duration::from_secs(60))
+        .expires(Duration::from_secs(3600))
+        .dead_letter_exchange("rust.tests.dlx")
+        .dead_letter_routing_key("rust.tests.dead")
+        .overflow(OverflowBehavior::RejectPublishDlx)
+        .build();
+
+    assert_eq!(
+        Value::Object(params.arguments.unwrap()),
+        json!({
+            "x-queue-type": "quorum",
+            "x-max-length": 10000,
+            "x-max-length-bytes": 67108864,
+            "x-message-ttl": 60000,
+            "x-expires": 3600000,
+            "x-dead-letter-exchange": "rust.tests.dlx",
+            "x-dead-letter-routing-key": "rust.tests.dead",
+            "x-overflow": "reject-publish-dlx"
+        })
+    );
+}
+
+#[test]
+fn test_queue_params_builder_with_queue_properties() {
+    let params = QueueParams::builder("rust.tests.builder.exclusive")
+        .durable(false)
+        .auto_delete(true)
+        .exclusive(true)
+        .argument("x-single-active-consumer", true)
+        .build();
+
+    assert!(!params.durable);
+    assert!(params.auto_delete);
+    assert!(params.exclusive);
+    assert_eq!(
+        Value::Object(params.arguments.unwrap()),
+        json!({"x-queue-type": "classic", "x-single-active-consumer": true})
+    );
+}
+
+#[test]
+fn test_overflow_behavior_serialization() {
+    assert_eq!(json!(OverflowBehavior::DropHead), json!("drop-head"));
+    assert_eq!(
+        json!(OverflowBehavior::RejectPublish),
+        json!("reject-publish")
+    );
+    assert_eq!(
+        json!(OverflowBehavior::RejectPublishDlx),
+        json!("reject-publish-dlx")
+    );
+}
+
+#[test]
+fn test_overflow_behavior_round_trip() {
+    let values = [
+        OverflowBehavior::DropHead,
+        OverflowBehavior::RejectPublish,
+        OverflowBehavior::RejectPublishDlx,
+        OverflowBehavior::Other("x-unknown-behavior".to_owned()),
+    ];
+    for value in values {
+        let serialized = serde_json::to_string(&value).unwrap();
+        let deserialized: OverflowBehavior = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, value);
+    }
+}
+
+#[test]
+fn test_unknown_overflow_behavior_deserialization() {
+    let result: OverflowBehavior = serde_json::from_str(r#""x-unknown-behavior""#).unwrap();
+    assert_eq!(
+        result,
+        OverflowBehavior::Other("x-unknown-behavior".to_owned())
+    );
+    assert_eq!(result.to_string(), "x-unknown-behavior");
+}
+
+#[test]
+fn test_policy_definition_overflow_behavior() {
+    let defs: PolicyDefinition =
+        serde_json::from_value(json!({"max-length": 1000, "overflow": "reject-publish"})).unwrap();
+    assert_eq!(
+        defs.overflow_behavior(),
+        Some(OverflowBehavior::RejectPublish)
+    );
+
+    let defs: PolicyDefinition = serde_json::from_value(json!({"max-length": 1000})).unwrap();
+    assert_eq!(defs.overflow_behavior(), None);
+}
@@ -11,11 +11,18 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{blocking_api::Client, commons::QueueType, requests::QueueParams};
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    commons::{OverflowBehavior, QueueType},
+    error::Error,
+    requests::QueueParams,
+    responses::{QueueDepth, QueueInfo, XArguments},
+};
+use reqwest::Method;
 use serde_json::{json, Map, Value};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_declare_and_redeclare_a_classic_queue() {
@@ -146,3 +153,401 @@ fn test_list_queues_in_a_virtual_host() {
 
     rc.delete_queue(vh_name, params.name, false).unwrap();
 }
+
+#[test]
+fn test_replica_status_of_a_quorum_queue() {
+    let qi: QueueInfo = serde_json::from_str(include_str!("fixtures/queue_quorum.json")).unwrap();
+    let status = qi.replica_status();
+
+    assert_eq!(status.leader, Some("rabbit@node1".to_owned()));
+    assert_eq!(status.in_sync_replicas, vec!["rabbit@node2".to_owned()]);
+    assert_eq!(status.out_of_sync_replicas, vec!["rabbit@node3".to_owned()]);
+    assert!(!status.is_fully_in_sync());
+}
+
+#[test]
+fn test_replica_status_of_a_mirrored_classic_queue() {
+    let qi: QueueInfo =
+        serde_json::from_str(include_str!("fixtures/queue_classic_mirrored.json")).unwrap();
+    let status = qi.replica_status();
+
+    assert_eq!(status.leader, Some("rabbit@node1".to_owned()));
+    assert_eq!(status.in_sync_replicas, vec!["rabbit@node2".to_owned()]);
+    assert_eq!(status.out_of_sync_replicas, vec!["rabbit@node3".to_owned()]);
+    assert!(!status.is_fully_in_sync());
+}
+
+#[test]
+fn test_queue_info_of_a_stream_with_absent_properties() {
+    let qi: QueueInfo = serde_json::from_str(include_str!("fixtures/queue_stream.json")).unwrap();
+
+    assert_eq!(qi.name, "events.stream");
+    assert_eq!(qi.queue_type, "stream");
+    assert!(qi.durable);
+    assert!(!qi.auto_delete);
+    assert!(!qi.exclusive);
+    assert!(qi.arguments.0.is_empty());
+    assert_eq!(qi.consumer_utilisation, 0.0);
+    assert_eq!(qi.message_bytes, 0);
+    assert_eq!(qi.message_count, 125000);
+    assert!(qi.replica_status().is_fully_in_sync());
+}
+
+#[test]
+fn test_queue_info_of_a_stream_with_stream_specific_fields() {
+    let qi: QueueInfo =
+        serde_json::from_str(include_str!("fixtures/queue_stream_with_arguments.json")).unwrap();
+
+    assert_eq!(qi.name, "events.stream.2");
+    assert_eq!(qi.queue_type, "stream");
+    assert_eq!(qi.leader.as_deref(), Some("rabbit@node2"));
+    assert_eq!(qi.members.as_ref().unwrap().0.len(), 3);
+    assert_eq!(qi.arguments.0["x-max-age"], json!("7D"));
+    assert_eq!(
+        qi.arguments.0["x-stream-max-segment-size-bytes"],
+        json!(100000000)
+    );
+    assert_eq!(qi.consumer_count, 2);
+    assert_eq!(qi.consumer_utilisation, 0.0);
+    assert_eq!(qi.memory, 0);
+    assert_eq!(qi.message_count, 4096);
+
+    let status = qi.replica_status();
+    assert_eq!(status.out_of_sync_replicas, vec!["rabbit@node3".to_owned()]);
+}
+
+#[test]
+fn test_sync_queue() {
+    let server = MockServer::start(vec![(204, "".to_owned()), (204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.sync_queue("/", "cmq.1");
+    assert!(result1.is_ok(), "sync_queue returned {:?}", result1);
+    let result2 = rc.cancel_queue_sync("/", "cmq.1");
+    assert!(result2.is_ok(), "cancel_queue_sync returned {:?}", result2);
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("POST /api/queues/%2F/cmq%2E1/actions "));
+    assert!(requests[1].starts_with("POST /api/queues/%2F/cmq%2E1/actions "));
+}
+
+#[test]
+fn test_sync_queue_of_an_inapplicable_type() {
+    let body = r#"{"error":"bad_request","reason":"not_mirrored"}"#;
+    let server = MockServer::start(vec![(400, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.sync_queue("/", "qq.1");
+    match result {
+        Err(Error::ClientErrorResponse {
+            status_code,
+            body: Some(b),
+            ..
+        }) => {
+            assert_eq!(status_code.as_u16(), 400);
+            assert_eq!(b, body);
+        }
+        other => panic!("expected a client error response, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_failed_queue_declaration_records_the_http_method() {
+    let body = r#"{"error":"bad_request","reason":"inequivalent arg 'durable'"}"#;
+    let server = MockServer::start(vec![(400, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = QueueParams::new_durable_classic_queue("cq.1", None);
+    let result = rc.declare_queue("/", &params);
+    match result {
+        Err(Error::ClientErrorResponse {
+            method,
+            status_code,
+            ..
+        }) => {
+            assert_eq!(method, Some(Method::PUT));
+            assert_eq!(status_code.as_u16(), 400);
+        }
+        other => panic!("expected a client error response, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_delete_queues_with_partial_failures() {
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (
+            404,
+            r#"{"error":"Object Not Found","reason":"Not Found"}"#.to_owned(),
+        ),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_queues("/", &["q.1", "q.2", "q.3"], false);
+    match result {
+        Err(Error::PartialFailure { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "q.2");
+            assert!(matches!(failures[0].1, Error::NotFound));
+        }
+        other => panic!("expected a PartialFailure error, got {:?}", other),
+    }
+
+    // all deletions are attempted
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[2].starts_with("DELETE /api/queues/%2F/q%2E3 "));
+}
+
+#[test]
+fn test_delete_queues() {
+    let server = MockServer::start(vec![(204, "".to_owned()), (204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_queues("/", &["q.1", "q.2"], false);
+    assert!(result.is_ok(), "delete_queues returned {:?}", result);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_purge_queues_with_missing_queues() {
+    let not_found = r#"{"error":"Object Not Found","reason":"Not Found"}"#;
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (404, not_found.to_owned()),
+        (204, "".to_owned()),
+        (404, not_found.to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.purge_queues("/", &["q.1", "q.2", "q.3", "q.4"]);
+    assert!(result.is_ok(), "purge_queues returned {:?}", result);
+    let report = result.unwrap();
+    assert_eq!(report.purged, vec!["q.1", "q.3"]);
+    assert_eq!(report.not_found, vec!["q.2", "q.4"]);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].starts_with("DELETE /api/queues/%2F/q%2E1/contents "));
+    assert!(requests[3].starts_with("DELETE /api/queues/%2F/q%2E4/contents "));
+}
+
+#[test]
+fn test_purge_queues_with_other_failures() {
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (500, r#"{"error":"internal_error"}"#.to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.purge_queues("/", &["q.1", "q.2"]);
+    match result {
+        Err(Error::PartialPurgeFailure { report, failures }) => {
+            assert_eq!(report.purged, vec!["q.1"]);
+            assert!(report.not_found.is_empty());
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "q.2");
+            assert!(matches!(failures[0].1, Error::ServerErrorResponse { .. }));
+        }
+        other => panic!("expected a PartialPurgeFailure error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_get_queue_depth() {
+    let body = include_str!("fixtures/queue_stream.json");
+    let server = MockServer::start(vec![(200, body.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.get_queue_depth("/", "rust.tests.stream.1");
+    assert!(result.is_ok(), "get_queue_depth returned {:?}", result);
+    let depth = result.unwrap();
+    assert_eq!(depth.messages, 125000);
+    assert_eq!(depth.messages_ready, 125000);
+    assert_eq!(depth.messages_unacknowledged, 0);
+    assert_eq!(depth.consumers, 1);
+
+    assert!(server.requests()[0].starts_with("GET /api/queues/%2F/rust%2Etests%2Estream%2E1 "));
+}
+
+#[test]
+fn test_queue_depth_with_missing_counts() {
+    // e.g. a queue that is not yet available on the node that served the request
+    let depth: QueueDepth = serde_json::from_value(json!({"name": "cq.1", "vhost": "/"})).unwrap();
+    assert_eq!(depth, QueueDepth::default());
+}
+
+#[test]
+fn test_grow_quorum_queue_membership() {
+    let queue = include_str!("fixtures/queue_quorum.json");
+    let server = MockServer::start(vec![(204, "".to_owned()), (200, queue.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.grow_quorum_queue_membership("/", "rust.tests.qq.1", "rabbit@node3");
+    assert!(
+        result.is_ok(),
+        "grow_quorum_queue_membership returned {:?}",
+        result
+    );
+    let change = result.unwrap();
+    assert_eq!(change.queue, "rust.tests.qq.1");
+    assert_eq!(change.node, "rabbit@node3");
+    assert_eq!(
+        change.members.0,
+        vec!["rabbit@node1", "rabbit@node2", "rabbit@node3"]
+    );
+
+    let requests = server.requests();
+    assert!(
+        requests[0].starts_with("POST /api/queues/quorum/%2F/rust%2Etests%2Eqq%2E1/replicas/add ")
+    );
+    assert!(requests[1].starts_with("GET /api/queues/%2F/rust%2Etests%2Eqq%2E1 "));
+    let body: Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+    assert_eq!(body, json!({"node": "rabbit@node3"}));
+}
+
+#[test]
+fn test_shrink_quorum_queue_membership() {
+    let mut queue: Value =
+        serde_json::from_str(include_str!("fixtures/queue_quorum.json")).unwrap();
+    queue["members"] = json!(["rabbit@node1", "rabbit@node2"]);
+    let server = MockServer::start(vec![(204, "".to_owned()), (200, queue.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.shrink_quorum_queue_membership("/", "rust.tests.qq.1", "rabbit@node3");
+    assert!(
+        result.is_ok(),
+        "shrink_quorum_queue_membership returned {:?}",
+        result
+    );
+    assert_eq!(
+        result.unwrap().members.0,
+        vec!["rabbit@node1", "rabbit@node2"]
+    );
+
+    let requests = server.requests();
+    assert!(requests[0]
+        .starts_with("DELETE /api/queues/quorum/%2F/rust%2Etests%2Eqq%2E1/replicas/delete "));
+    let body: Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+    assert_eq!(body, json!({"node": "rabbit@node3"}));
+}
+
+#[test]
+fn test_queue_info_with_message_stats() {
+    let json = include_str!("fixtures/queue_with_message_stats.json");
+    let queue: QueueInfo = serde_json::from_str(json).unwrap();
+
+    let stats = queue.message_stats.unwrap();
+    assert_eq!(stats.publish, Some(1500));
+    assert_eq!(stats.deliver_get, Some(1488));
+    assert_eq!(stats.ack, Some(1486));
+    assert_eq!(stats.redeliver, Some(3));
+    assert_eq!(stats.publishing_details.rate, 25.0);
+    assert_eq!(stats.delivery_details.rate, 24.4);
+    assert_eq!(stats.consumer_acknowledgement_details.rate, 24.0);
+}
+
+#[test]
+fn test_queue_info_without_message_stats() {
+    let json = json!({
+        "name": "cq.1",
+        "vhost": "/",
+        "type": "classic",
+        "durable": true,
+        "auto_delete": false,
+        "exclusive": false,
+        "arguments": {}
+    });
+    let queue: QueueInfo = serde_json::from_value(json).unwrap();
+    assert!(queue.message_stats.is_none());
+}
+
+#[test]
+fn test_queue_type_from_str_accepts_aliases() {
+    let cases = [
+        ("classic", QueueType::Classic),
+        ("Classic", QueueType::Classic),
+        ("CQ", QueueType::Classic),
+        ("cq", QueueType::Classic),
+        ("quorum", QueueType::Quorum),
+        ("QUORUM", QueueType::Quorum),
+        ("QQ", QueueType::Quorum),
+        ("qq", QueueType::Quorum),
+        ("stream", QueueType::Stream),
+        ("Stream", QueueType::Stream),
+        ("SQ", QueueType::Stream),
+        ("sq", QueueType::Stream),
+    ];
+
+    for (s, expected) in cases {
+        let parsed: QueueType = s.parse().unwrap();
+        assert_eq!(parsed, expected, "parsing {:?}", s);
+    }
+}
+
+#[test]
+fn test_queue_type_display_uses_canonical_names() {
+    assert_eq!(QueueType::Classic.to_string(), "classic");
+    assert_eq!(QueueType::Quorum.to_string(), "quorum");
+    assert_eq!(QueueType::Stream.to_string(), "stream");
+
+    let parsed: QueueType = "QQ".parse().unwrap();
+    assert_eq!(parsed.to_string(), "quorum");
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), json!("quorum"));
+}
+
+#[test]
+fn test_queue_type_unsupported_round_trip() {
+    let parsed: QueueType = "x-delayed".parse().unwrap();
+    assert_eq!(parsed, QueueType::Unsupported("x-delayed".to_owned()));
+    assert_eq!(parsed.to_string(), "x-delayed");
+
+    let serialized = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(serialized, json!("x-delayed"));
+    let deserialized: QueueType = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, parsed);
+
+    let args = QueueParams::combined_args(None, &parsed).unwrap();
+    assert_eq!(args["x-queue-type"], json!("x-delayed"));
+}
+
+#[test]
+fn test_x_arguments_typed_accessors_with_numeric_values() {
+    let args: XArguments = serde_json::from_value(json!({
+        "x-queue-type": "quorum",
+        "x-max-length": 10000,
+        "x-max-length-bytes": 1048576,
+        "x-message-ttl": 60000,
+        "x-expires": 1800000,
+        "x-dead-letter-exchange": "orders.dlx",
+        "x-dead-letter-routing-key": "orders.dead",
+        "x-overflow": "reject-publish"
+    }))
+    .unwrap();
+
+    assert_eq!(args.queue_type(), Some(QueueType::Quorum));
+    assert_eq!(args.max_length(), Some(10000));
+    assert_eq!(args.max_length_bytes(), Some(1048576));
+    assert_eq!(args.message_ttl(), Some(60000));
+    assert_eq!(args.expires(), Some(1800000));
+    assert_eq!(args.dead_letter_exchange(), Some("orders.dlx"));
+    assert_eq!(args.dead_letter_routing_key(), Some("orders.dead"));
+    assert_eq!(args.overflow(), Some(OverflowBehavior::RejectPublish));
+}
+
+#[test]
+fn test_x_arguments_typed_accessors_with_string_encoded_values() {
+    let args: XArguments = serde_json::from_value(json!({
+        "x-max-length": "10000",
+        "x-message-ttl": "60000",
+        "x-expires": "not a number"
+    }))
+    .unwrap();
+
+    assert_eq!(args.max_length(), Some(10000));
+    assert_eq!(args.message_ttl(), Some(60000));
+    assert_eq!(args.expires(), None);
+    assert_eq!(args.queue_type(), None);
+    assert_eq!(args.dead_letter_exchange(), None);
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use rabbitmq_http_client::blocking_api::{Client, ClientBuilder};
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+const OVERVIEW: &str = include_str!("fixtures/overview_oss.json");
+
+// accepts connections (via the listen backlog) but never responds
+fn unresponsive_endpoint() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/api", listener.local_addr().unwrap());
+    (listener, endpoint)
+}
+
+#[test]
+fn test_default_timeout_is_applied_to_requests() {
+    let (_listener, endpoint) = unresponsive_endpoint();
+    let rc = ClientBuilder::new()
+        .with_endpoint(endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_default_timeout(Duration::from_millis(200))
+        .build();
+
+    let started_at = Instant::now();
+    let result = rc.overview();
+    assert!(result.is_err());
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn test_default_timeout_does_not_affect_fast_responses() {
+    let server = MockServer::start(vec![(200, OVERVIEW.to_owned())]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_default_timeout(Duration::from_secs(5))
+        .build();
+
+    let result = rc.overview();
+    assert!(result.is_ok(), "overview returned {:?}", result);
+}
+
+#[test]
+fn test_export_cluster_wide_definitions_with_timeout() {
+    let (_listener, endpoint) = unresponsive_endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let started_at = Instant::now();
+    let result = rc.export_cluster_wide_definitions_with_timeout(Duration::from_millis(200));
+    assert!(result.is_err());
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use rabbitmq_http_client::{
+    blocking_api::{Client, ClientBuilder},
+    error::Error,
+    retries::RetryPolicy,
+};
+use reqwest::StatusCode;
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+const OVERVIEW: &str = include_str!("fixtures/overview_oss.json");
+
+fn unavailable() -> (u16, String) {
+    (503, r#"{"error": "service_unavailable"}"#.to_owned())
+}
+
+#[test]
+fn test_idempotent_requests_are_retried() {
+    let server = MockServer::start(vec![
+        unavailable(),
+        unavailable(),
+        (200, OVERVIEW.to_owned()),
+    ]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(10)))
+        .build();
+
+    let result = rc.overview();
+    assert!(result.is_ok(), "overview returned {:?}", result);
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn test_retries_stop_after_max_attempts() {
+    let server = MockServer::start(vec![
+        unavailable(),
+        unavailable(),
+        (200, OVERVIEW.to_owned()),
+    ]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(10)))
+        .build();
+
+    let result = rc.overview();
+    assert!(matches!(
+        result,
+        Err(Error::ServerErrorResponse {
+            status_code: StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        })
+    ));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_post_requests_are_not_retried_by_default() {
+    let server = MockServer::start(vec![unavailable(), (204, "".to_owned())]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(10)))
+        .build();
+
+    let result = rc.rebalance_queue_leaders();
+    assert!(result.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_non_retryable_status_codes_are_not_retried() {
+    let server = MockServer::start(vec![(500, "{}".to_owned()), (200, OVERVIEW.to_owned())]);
+    let rc = ClientBuilder::new()
+        .with_endpoint(server.endpoint.as_str())
+        .with_basic_auth_credentials(USERNAME, PASSWORD)
+        .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(10)))
+        .build();
+
+    let result = rc.overview();
+    assert!(result.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_requests_are_not_retried_without_a_policy() {
+    let server = MockServer::start(vec![unavailable(), (200, OVERVIEW.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.overview();
+    assert!(result.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_retry_policy_delays() {
+    let policy = RetryPolicy::new(4, Duration::from_millis(100));
+
+    assert_eq!(policy.delay_after(1), Duration::from_millis(100));
+    assert_eq!(policy.delay_after(2), Duration::from_millis(200));
+    assert_eq!(policy.delay_after(3), Duration::from_millis(400));
+}
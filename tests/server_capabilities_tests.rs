@@ -0,0 +1,52 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{
+    commons::RatesMode,
+    responses::{Overview, ServerCapabilities},
+};
+
+const OSS_OVERVIEW: &str = include_str!("fixtures/overview_oss.json");
+const TANZU_OVERVIEW: &str = include_str!("fixtures/overview_tanzu.json");
+
+#[test]
+fn test_server_capabilities_of_an_oss_node() {
+    let overview: Overview = serde_json::from_str(OSS_OVERVIEW).unwrap();
+    let caps = ServerCapabilities::from(&overview);
+
+    assert_eq!(caps.rabbitmq_version, "4.0.5");
+    assert_eq!(caps.management_version, "4.0.5");
+    assert_eq!(caps.rates_mode, Some(RatesMode::Basic));
+    assert_eq!(caps.product_name, "RabbitMQ");
+    assert!(!caps.is_tanzu);
+    assert!(caps.supports_detailed_queues);
+    assert!(caps.supports_certificate_expiration_check);
+    assert!(caps.supports_deprecated_features);
+    assert!(caps.supports_cluster_tags);
+}
+
+#[test]
+fn test_server_capabilities_of_a_tanzu_node() {
+    let overview: Overview = serde_json::from_str(TANZU_OVERVIEW).unwrap();
+    let caps = ServerCapabilities::from(&overview);
+
+    assert_eq!(caps.rabbitmq_version, "3.13.3+1.gb84c1fe");
+    assert_eq!(caps.rates_mode, Some(RatesMode::Detailed));
+    assert_eq!(caps.product_name, "VMware Tanzu RabbitMQ");
+    assert_eq!(caps.product_version, "3.13.3");
+    assert!(caps.is_tanzu);
+    assert!(!caps.supports_detailed_queues);
+    assert!(caps.supports_certificate_expiration_check);
+    assert!(caps.supports_deprecated_features);
+    assert!(!caps.supports_cluster_tags);
+}
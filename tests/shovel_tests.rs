@@ -0,0 +1,363 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    error::{Error, ShovelValidationError},
+    requests::{Amqp091ShovelParams, Amqp10ShovelParams},
+    responses::{Shovel, ShovelState, ShovelType},
+};
+use serde_json::{json, Value};
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+#[test]
+fn test_restart_shovel() {
+    let server = MockServer::start(vec![(204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.restart_shovel("/", "shovel.1");
+    assert!(result.is_ok(), "restart_shovel returned {:?}", result);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("DELETE /api/shovels/vhost/%2F/shovel%2E1/restart "));
+}
+
+#[test]
+fn test_restart_shovel_that_does_not_exist() {
+    let server = MockServer::start(vec![(
+        404,
+        r#"{"error":"Object Not Found","reason":"Not Found"}"#.to_owned(),
+    )]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.restart_shovel("/", "shovel.404");
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "restart_shovel returned {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_delete_shovel() {
+    let server = MockServer::start(vec![(204, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_shovel("/", "shovel.1");
+    assert!(result.is_ok(), "delete_shovel returned {:?}", result);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("DELETE /api/parameters/shovel/%2F/shovel%2E1 "));
+}
+
+const SHOVELS: &str = r#"[
+    {
+        "node": "rabbit@sunnyside",
+        "timestamp": "2025-01-20 10:15:02",
+        "name": "shovel.1",
+        "vhost": "/",
+        "type": "dynamic",
+        "state": "running",
+        "src_uri": "amqp://",
+        "src_protocol": "amqp091",
+        "dest_protocol": "amqp091",
+        "dest_uri": "amqp://remote.local",
+        "src_queue": "source.1",
+        "dest_queue": "destination.1"
+    },
+    {
+        "node": "rabbit@sunnyside",
+        "timestamp": "2025-01-20 10:15:04",
+        "name": "shovel.2",
+        "vhost": "/",
+        "type": "dynamic",
+        "state": "terminated",
+        "reason": "needed a restart"
+    }
+]"#;
+
+#[test]
+fn test_get_shovel() {
+    let server = MockServer::start(vec![(200, SHOVELS.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.get_shovel("/", "shovel.1");
+    assert!(result.is_ok(), "get_shovel returned {:?}", result);
+    let shovel = result.unwrap();
+    assert_eq!(shovel.name, "shovel.1");
+    assert_eq!(shovel.state, ShovelState::Running);
+    assert_eq!(shovel.shovel_type, ShovelType::Dynamic);
+    assert_eq!(shovel.dest_queue, Some("destination.1".to_owned()));
+
+    assert!(server.requests()[0].starts_with("GET /api/shovels/%2F "));
+}
+
+#[test]
+fn test_shovel_state() {
+    let server = MockServer::start(vec![(200, SHOVELS.to_owned()), (200, SHOVELS.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result1 = rc.shovel_state("/", "shovel.1");
+    assert!(matches!(result1, Ok(ShovelState::Running)));
+
+    let result2 = rc.shovel_state("/", "shovel.2");
+    assert!(matches!(result2, Ok(ShovelState::Terminated)));
+}
+
+#[test]
+fn test_shovel_state_of_a_missing_shovel() {
+    let server = MockServer::start(vec![(200, SHOVELS.to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.shovel_state("/", "shovel.404");
+    assert!(
+        matches!(result, Err(Error::NotFound)),
+        "shovel_state returned {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_shovel_state_deserialization_of_an_unknown_state() {
+    let state: ShovelState = serde_json::from_str(r#""blocked""#).unwrap();
+    assert_eq!(state, ShovelState::Unknown("blocked".to_owned()));
+}
+
+#[test]
+fn test_shovel_deserialization_with_an_unknown_state_and_type() {
+    let json = r#"{
+        "node": "rabbit@sunnyside",
+        "timestamp": "2025-01-20 10:15:02",
+        "name": "shovel.3",
+        "vhost": "/",
+        "type": "ephemeral",
+        "state": "resuming"
+    }"#;
+    let shovel: Shovel = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        shovel.shovel_type,
+        ShovelType::Unknown("ephemeral".to_owned())
+    );
+    assert_eq!(shovel.state, ShovelState::Unknown("resuming".to_owned()));
+    assert_eq!(shovel.shovel_type.to_string(), "ephemeral");
+    assert_eq!(shovel.state.to_string(), "resuming");
+}
+
+#[test]
+fn test_shovel_type_and_state_display() {
+    assert_eq!(ShovelType::Dynamic.to_string(), "dynamic");
+    assert_eq!(ShovelType::Static.to_string(), "static");
+    assert_eq!(ShovelState::Starting.to_string(), "starting");
+    assert_eq!(ShovelState::Running.to_string(), "running");
+    assert_eq!(ShovelState::Terminated.to_string(), "terminated");
+}
+
+fn amqp091_shovel_params<'a>() -> Amqp091ShovelParams<'a> {
+    Amqp091ShovelParams {
+        vhost: "/",
+        name: "shovel.1",
+        source_uri: "amqp://source.local",
+        source_queue: Some("src.q"),
+        destination_uri: "amqp://destination.local",
+        destination_queue: Some("dest.q"),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_declare_amqp091_shovel() {
+    let server = MockServer::start(vec![(201, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.declare_amqp091_shovel(amqp091_shovel_params());
+    assert!(
+        result.is_ok(),
+        "declare_amqp091_shovel returned {:?}",
+        result
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("PUT /api/parameters/shovel/%2F/shovel%2E1 "));
+
+    let body: Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+    assert_eq!(
+        body["value"],
+        json!({
+            "src-protocol": "amqp091",
+            "src-uri": "amqp://source.local",
+            "src-queue": "src.q",
+            "dest-protocol": "amqp091",
+            "dest-uri": "amqp://destination.local",
+            "dest-queue": "dest.q"
+        })
+    );
+}
+
+#[test]
+fn test_amqp091_shovel_params_validation() {
+    let cases = [
+        (
+            Amqp091ShovelParams {
+                source_uri: "",
+                ..amqp091_shovel_params()
+            },
+            ShovelValidationError::MissingSourceUri,
+        ),
+        (
+            Amqp091ShovelParams {
+                destination_uri: "",
+                ..amqp091_shovel_params()
+            },
+            ShovelValidationError::MissingDestinationUri,
+        ),
+        (
+            Amqp091ShovelParams {
+                source_queue: None,
+                ..amqp091_shovel_params()
+            },
+            ShovelValidationError::MissingSource,
+        ),
+        (
+            Amqp091ShovelParams {
+                source_queue: Some(""),
+                ..amqp091_shovel_params()
+            },
+            ShovelValidationError::MissingSource,
+        ),
+        (
+            Amqp091ShovelParams {
+                source_exchange: Some("src.x"),
+                ..amqp091_shovel_params()
+            },
+            ShovelValidationError::AmbiguousSource,
+        ),
+        (
+            Amqp091ShovelParams {
+                destination_exchange: Some("dest.x"),
+                ..amqp091_shovel_params()
+            },
+            ShovelValidationError::AmbiguousDestination,
+        ),
+    ];
+
+    for (params, expected) in cases {
+        assert_eq!(params.validate(), Err(expected));
+    }
+
+    let params = Amqp091ShovelParams {
+        source_queue: None,
+        source_exchange: Some("src.x"),
+        destination_queue: None,
+        ..amqp091_shovel_params()
+    };
+    assert_eq!(params.validate(), Ok(()));
+}
+
+#[test]
+fn test_declare_amqp091_shovel_with_invalid_params_sends_no_requests() {
+    let server = MockServer::start(vec![]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = Amqp091ShovelParams {
+        source_queue: None,
+        ..amqp091_shovel_params()
+    };
+    let result = rc.declare_amqp091_shovel(params);
+    match result {
+        Err(Error::InvalidShovelDefinition { error }) => {
+            assert_eq!(error, ShovelValidationError::MissingSource)
+        }
+        other => panic!("expected an InvalidShovelDefinition error, got {:?}", other),
+    }
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_declare_amqp10_shovel() {
+    let server = MockServer::start(vec![(201, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = Amqp10ShovelParams {
+        vhost: "/",
+        name: "shovel.10",
+        source_uri: "amqp://source.local",
+        source_address: "/queues/src.q",
+        destination_uri: "amqp://destination.local",
+        destination_address: "/queues/dest.q",
+        reconnect_delay: Some(5),
+        ..Default::default()
+    };
+    let result = rc.declare_amqp10_shovel(params);
+    assert!(
+        result.is_ok(),
+        "declare_amqp10_shovel returned {:?}",
+        result
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("PUT /api/parameters/shovel/%2F/shovel%2E10 "));
+
+    let body: Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+    assert_eq!(body["value"]["src-protocol"], json!("amqp10"));
+    assert_eq!(body["value"]["src-address"], json!("/queues/src.q"));
+    assert_eq!(body["value"]["dest-address"], json!("/queues/dest.q"));
+    assert_eq!(body["value"]["reconnect-delay"], json!(5));
+}
+
+#[test]
+fn test_amqp10_shovel_params_validation() {
+    let valid = Amqp10ShovelParams {
+        vhost: "/",
+        name: "shovel.10",
+        source_uri: "amqp://source.local",
+        source_address: "/queues/src.q",
+        destination_uri: "amqp://destination.local",
+        destination_address: "/queues/dest.q",
+        ..Default::default()
+    };
+    assert_eq!(valid.validate(), Ok(()));
+
+    let cases = [
+        (
+            Amqp10ShovelParams {
+                source_uri: "",
+                ..valid.clone()
+            },
+            ShovelValidationError::MissingSourceUri,
+        ),
+        (
+            Amqp10ShovelParams {
+                source_address: "",
+                ..valid.clone()
+            },
+            ShovelValidationError::MissingSourceAddress,
+        ),
+        (
+            Amqp10ShovelParams {
+                destination_address: "",
+                ..valid.clone()
+            },
+            ShovelValidationError::MissingDestinationAddress,
+        ),
+    ];
+
+    for (params, expected) in cases {
+        assert_eq!(params.validate(), Err(expected));
+    }
+}
@@ -14,7 +14,8 @@
 use rabbitmq_http_client::blocking_api::Client;
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
+use serde_json::json;
 
 #[test]
 fn test_list_stream_consumers() {
@@ -42,3 +43,50 @@ fn test_list_virtual_host_stream_consumers() {
         result1
     );
 }
+
+#[test]
+fn test_list_stream_consumers_of() {
+    let consumers = json!([
+        {"connection_details": {"name": "127.0.0.1:52000 -> 127.0.0.1:5552",
+                                "peer_host": "127.0.0.1", "peer_port": 52000},
+         "queue": {"name": "events", "vhost": "vh.streams"},
+         "subscription_id": 1, "credits": 10, "consumed": 100, "offset_lag": 0, "offset": 99,
+         "properties": {}},
+        {"connection_details": {"name": "127.0.0.1:52001 -> 127.0.0.1:5552",
+                                "peer_host": "127.0.0.1", "peer_port": 52001},
+         "queue": {"name": "audit", "vhost": "vh.streams"},
+         "subscription_id": 1, "credits": 10, "consumed": 7, "offset_lag": 3, "offset": 6,
+         "properties": {}}
+    ]);
+    let server = MockServer::start(vec![(200, consumers.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_stream_consumers_of("vh.streams", "events");
+    assert!(
+        result.is_ok(),
+        "list_stream_consumers_of returned {:?}",
+        result
+    );
+    let vec = result.unwrap();
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].queue.name, "events");
+    assert_eq!(vec[0].consumed, 100);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/stream/consumers/vh%2Estreams "));
+}
+
+#[test]
+fn test_list_stream_consumers_of_a_stream_without_consumers() {
+    let server = MockServer::start(vec![(200, "[]".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.list_stream_consumers_of("vh.streams", "events");
+    assert!(
+        result.is_ok(),
+        "list_stream_consumers_of returned {:?}",
+        result
+    );
+    assert!(result.unwrap().is_empty());
+}
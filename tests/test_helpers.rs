@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use std::env;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::Duration;
 
+#[allow(dead_code)]
 pub const ENDPOINT: &str = "http://localhost:15672/api";
 pub const USERNAME: &str = "guest";
 pub const PASSWORD: &str = "guest";
 
+#[allow(dead_code)]
 pub fn endpoint() -> String {
     ENDPOINT.to_owned()
 }
@@ -32,3 +38,118 @@ pub fn await_queue_metric_emission() {
     let delay = env::var("TEST_STATS_DELAY").unwrap_or("500".to_owned());
     await_metric_emission(delay.parse::<u64>().unwrap());
 }
+
+/// Request headers (lowercase name and value) in the order they were received
+type RequestHeaders = Vec<(String, String)>;
+
+/// A minimal HTTP server that responds with the provided canned responses
+/// (status code and JSON body), in order, and records the request lines
+/// (e.g. `GET /api/queues?page=1 HTTP/1.1`), headers and bodies it has received.
+///
+/// Used to test client behavior that cannot be easily reproduced with a real node.
+#[allow(dead_code)]
+pub struct MockServer {
+    pub endpoint: String,
+    requests: Arc<Mutex<Vec<String>>>,
+    headers: Arc<Mutex<Vec<RequestHeaders>>>,
+    bodies: Arc<Mutex<Vec<String>>>,
+}
+
+#[allow(dead_code)]
+impl MockServer {
+    pub fn start(responses: Vec<(u16, String)>) -> Self {
+        Self::start_with_content_type("application/json", responses)
+    }
+
+    /// Like [`MockServer::start`] but responds with the given content type
+    pub fn start_with_content_type(content_type: &str, responses: Vec<(u16, String)>) -> Self {
+        let content_type = content_type.to_owned();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        let headers = Arc::new(Mutex::new(Vec::new()));
+        let recorded_headers = Arc::clone(&headers);
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let recorded_bodies = Arc::clone(&bodies);
+
+        thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for stream in listener.incoming() {
+                let Ok(mut writer) = stream else { break };
+                let mut reader = BufReader::new(writer.try_clone().unwrap());
+                loop {
+                    let mut request_line = String::new();
+                    if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                        break;
+                    }
+
+                    let mut content_length = 0;
+                    let mut request_headers = Vec::new();
+                    loop {
+                        let mut header = String::new();
+                        if reader.read_line(&mut header).unwrap_or(0) == 0 || header == "\r\n" {
+                            break;
+                        }
+                        if let Some((k, v)) = header.split_once(':') {
+                            if k.eq_ignore_ascii_case("content-length") {
+                                content_length = v.trim().parse().unwrap_or(0);
+                            }
+                            request_headers.push((k.to_lowercase(), v.trim().to_owned()));
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    let _ = reader.read_exact(&mut body);
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push(request_line.trim_end().to_owned());
+                    recorded_headers.lock().unwrap().push(request_headers);
+                    recorded_bodies
+                        .lock()
+                        .unwrap()
+                        .push(String::from_utf8_lossy(&body).into_owned());
+
+                    let (status, payload) = responses.next().unwrap_or((500, "{}".to_owned()));
+                    let response = format!(
+                        "HTTP/1.1 {} Mock\r\ncontent-type: {}\r\ncontent-length: {}\r\n\r\n{}",
+                        status,
+                        content_type,
+                        payload.len(),
+                        payload
+                    );
+                    if writer.write_all(response.as_bytes()).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        MockServer {
+            endpoint: format!("http://127.0.0.1:{}/api", port),
+            requests,
+            headers,
+            bodies,
+        }
+    }
+
+    /// Returns the request lines received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns the bodies of the requests received so far, empty for requests without a body
+    pub fn bodies(&self) -> Vec<String> {
+        self.bodies.lock().unwrap().clone()
+    }
+
+    /// Returns the value of a header (by its lowercase name) of every request received so far
+    pub fn header_values(&self, name: &str) -> Vec<Option<String>> {
+        self.headers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|hs| hs.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone()))
+            .collect()
+    }
+}
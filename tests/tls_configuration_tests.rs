@@ -0,0 +1,97 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "tls")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use rabbitmq_http_client::{blocking_api::ClientBuilder, error::Error};
+
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rabbitmq_http_client_{}", name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_with_tls_and_a_missing_ca_certificate_file() {
+    let path = std::env::temp_dir().join("rabbitmq_http_client_missing_ca_certificate.pem");
+    let result = ClientBuilder::new().with_tls(Some(path), None);
+    match result {
+        Err(Error::InvalidTlsConfiguration { reason }) => {
+            assert!(
+                reason.contains("could not read"),
+                "unexpected reason: {}",
+                reason
+            );
+            assert!(reason.contains("missing_ca_certificate.pem"));
+        }
+        Err(other) => panic!("expected an InvalidTlsConfiguration error, got {:?}", other),
+        Ok(_) => panic!("expected an InvalidTlsConfiguration error"),
+    }
+}
+
+#[test]
+fn test_with_tls_and_an_unparseable_ca_certificate() {
+    let path = temp_file("unparseable_ca_certificate.pem", "not a PEM file");
+    let result = ClientBuilder::new().with_tls(Some(path), None);
+    match result {
+        Err(Error::InvalidTlsConfiguration { reason }) => {
+            assert!(
+                reason.contains("could not parse CA certificate"),
+                "unexpected reason: {}",
+                reason
+            );
+        }
+        Err(other) => panic!("expected an InvalidTlsConfiguration error, got {:?}", other),
+        Ok(_) => panic!("expected an InvalidTlsConfiguration error"),
+    }
+}
+
+#[test]
+fn test_with_tls_and_a_missing_client_key_file() {
+    let certificate_path = temp_file("client_certificate.pem", "not a PEM file");
+    let key_path = std::env::temp_dir().join("rabbitmq_http_client_missing_client_key.pem");
+    let result = ClientBuilder::new().with_tls(None, Some((certificate_path, key_path)));
+    match result {
+        Err(Error::InvalidTlsConfiguration { reason }) => {
+            assert!(
+                reason.contains("could not read"),
+                "unexpected reason: {}",
+                reason
+            );
+            assert!(reason.contains("missing_client_key.pem"));
+        }
+        Err(other) => panic!("expected an InvalidTlsConfiguration error, got {:?}", other),
+        Ok(_) => panic!("expected an InvalidTlsConfiguration error"),
+    }
+}
+
+#[test]
+fn test_with_tls_and_an_unparseable_client_identity() {
+    let certificate_path = temp_file("unparseable_client_certificate.pem", "not a PEM file");
+    let key_path = temp_file("unparseable_client_key.pem", "not a PEM file either");
+    let result = ClientBuilder::new().with_tls(None, Some((certificate_path, key_path)));
+    match result {
+        Err(Error::InvalidTlsConfiguration { reason }) => {
+            assert!(
+                reason.contains("could not parse client certificate"),
+                "unexpected reason: {}",
+                reason
+            );
+        }
+        Err(other) => panic!("expected an InvalidTlsConfiguration error, got {:?}", other),
+        Ok(_) => panic!("expected an InvalidTlsConfiguration error"),
+    }
+}
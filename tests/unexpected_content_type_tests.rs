@@ -0,0 +1,55 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{blocking_api::Client, error::Error};
+
+mod test_helpers;
+use crate::test_helpers::{MockServer, PASSWORD, USERNAME};
+
+#[test]
+fn test_html_response_produces_an_unexpected_content_type_error() {
+    let html = "<!DOCTYPE html><html><head><title>404 Not Found</title></head><body><h1>Not Found</h1></body></html>";
+    let server = MockServer::start_with_content_type(
+        "text/html; charset=utf-8",
+        vec![(404, html.to_owned()), (200, html.to_owned())],
+    );
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    for _ in 0..2 {
+        let result = rc.overview();
+        match result {
+            Err(Error::UnexpectedContentType {
+                content_type,
+                body_prefix,
+            }) => {
+                assert_eq!(content_type, "text/html; charset=utf-8");
+                assert!(body_prefix.starts_with("<!DOCTYPE html>"));
+            }
+            other => panic!("expected an UnexpectedContentType error, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_unexpected_content_type_error_includes_only_a_body_prefix() {
+    let body = "x".repeat(10_000);
+    let server = MockServer::start_with_content_type("text/plain", vec![(200, body)]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    match rc.overview() {
+        Err(Error::UnexpectedContentType { body_prefix, .. }) => {
+            assert!(body_prefix.len() < 10_000)
+        }
+        other => panic!("expected an UnexpectedContentType error, got {:?}", other),
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2023-2025 RabbitMQ Core Team (teamrabbitmq@gmail.com)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use rabbitmq_http_client::{
+    commons::{ByteSize, MillisDuration, PolicyTarget},
+    requests::{PolicyParams, QueueParams},
+};
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn test_byte_size_serializes_to_an_integer() {
+    assert_eq!(json!(ByteSize::bytes(512)), json!(512));
+    assert_eq!(json!(ByteSize::kb(2)), json!(2000));
+    assert_eq!(json!(ByteSize::kib(2)), json!(2048));
+    assert_eq!(json!(ByteSize::mb(1)), json!(1_000_000));
+    assert_eq!(json!(ByteSize::mib(100)), json!(104_857_600));
+    assert_eq!(json!(ByteSize::gb(1)), json!(1_000_000_000));
+    assert_eq!(json!(ByteSize::gib(1)), json!(1_073_741_824));
+}
+
+#[test]
+fn test_millis_duration_serializes_to_an_integer() {
+    assert_eq!(json!(MillisDuration::millis(250)), json!(250));
+    assert_eq!(json!(MillisDuration::seconds(30)), json!(30_000));
+    assert_eq!(json!(MillisDuration::minutes(5)), json!(300_000));
+    assert_eq!(json!(MillisDuration::hours(1)), json!(3_600_000));
+    assert_eq!(json!(MillisDuration::days(1)), json!(86_400_000));
+    assert_eq!(
+        json!(MillisDuration::from(Duration::from_secs(2))),
+        json!(2000)
+    );
+}
+
+#[test]
+fn test_unit_conversions_saturate_on_overflow() {
+    assert_eq!(ByteSize::gib(u64::MAX / 1024), ByteSize(u64::MAX));
+    assert_eq!(ByteSize::kb(u64::MAX), ByteSize(u64::MAX));
+    assert_eq!(
+        MillisDuration::days(u64::MAX / 1000),
+        MillisDuration(u64::MAX)
+    );
+    assert_eq!(
+        MillisDuration::from(Duration::MAX),
+        MillisDuration(u64::MAX)
+    );
+}
+
+#[test]
+fn test_queue_params_accept_typed_arguments() {
+    let params = QueueParams::new_quorum_queue("rust.tests.qq.unit_conversions", None)
+        .with_message_ttl(MillisDuration::seconds(30))
+        .with_expires(MillisDuration::minutes(10))
+        .with_max_length_bytes(ByteSize::mib(100));
+    let args = json!(params)["arguments"].clone();
+
+    assert_eq!(args["x-queue-type"], json!("quorum"));
+    assert_eq!(args["x-message-ttl"], json!(30_000));
+    assert_eq!(args["x-expires"], json!(600_000));
+    assert_eq!(args["x-max-length-bytes"], json!(104_857_600));
+}
+
+#[test]
+fn test_stream_params_accept_typed_arguments() {
+    let params = QueueParams::new_stream("rust.tests.stream.unit_conversions", None)
+        .with_max_length_bytes(ByteSize::gib(2))
+        .with_stream_max_segment_size_bytes(ByteSize::mib(50));
+    let args = json!(params)["arguments"].clone();
+
+    assert_eq!(args["x-max-length-bytes"], json!(2_147_483_648_u64));
+    assert_eq!(args["x-stream-max-segment-size-bytes"], json!(52_428_800));
+}
+
+#[test]
+fn test_policy_params_accept_typed_arguments() {
+    let params = PolicyParams {
+        vhost: "/",
+        name: "rust.tests.policy.unit_conversions",
+        pattern: ".*",
+        apply_to: PolicyTarget::Queues,
+        priority: 1,
+        definition: None,
+    }
+    .with_message_ttl(MillisDuration::seconds(5))
+    .with_max_length_bytes(ByteSize::kib(64));
+    let definition = json!(params)["definition"].clone();
+
+    assert_eq!(definition["message-ttl"], json!(5000));
+    assert_eq!(definition["max-length-bytes"], json!(65_536));
+}
+
+#[test]
+fn test_stream_params_use_canonical_retention_argument_keys() {
+    let params = QueueParams::new_stream("rust.tests.stream.retention", None)
+        .with_max_length_bytes(ByteSize::gib(20))
+        .with_stream_max_segment_size_bytes(ByteSize::mib(100))
+        .with_stream_max_age(Duration::from_secs(7 * 24 * 3600));
+
+    let value = serde_json::to_value(&params).unwrap();
+    let args = &value["arguments"];
+    assert_eq!(args["x-queue-type"], json!("stream"));
+    assert_eq!(args["x-max-length-bytes"], json!(21_474_836_480u64));
+    assert_eq!(args["x-stream-max-segment-size-bytes"], json!(104_857_600));
+    assert_eq!(args["x-max-age"], json!("7D"));
+}
+
+#[test]
+fn test_stream_max_age_formatting() {
+    let max_age = |d: Duration| {
+        let params =
+            QueueParams::new_stream("rust.tests.stream.max_age", None).with_stream_max_age(d);
+        params.arguments.unwrap()["x-max-age"].clone()
+    };
+
+    assert_eq!(max_age(Duration::from_secs(12 * 3600)), json!("12h"));
+    assert_eq!(max_age(Duration::from_secs(90 * 60)), json!("90m"));
+    assert_eq!(max_age(Duration::from_secs(45)), json!("45s"));
+    assert_eq!(max_age(Duration::from_secs(2 * 86_400)), json!("2D"));
+}
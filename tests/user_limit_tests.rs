@@ -14,12 +14,13 @@
 use rabbitmq_http_client::{
     blocking_api::Client,
     commons::UserLimitTarget,
+    error::Error,
     password_hashing,
     requests::{EnforcedLimitParams, UserParams},
 };
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_all_user_limits() {
@@ -34,6 +35,7 @@ fn test_list_all_user_limits() {
         name: "test_list_all_user_limits",
         password_hash: &password_hash,
         tags: "management",
+        hashing_algorithm: None,
     };
     let result1 = rc.create_user(&params);
     assert!(result1.is_ok());
@@ -72,6 +74,7 @@ fn test_list_user_limits() {
         name: "test_list_user_limits",
         password_hash: &password_hash,
         tags: "management",
+        hashing_algorithm: None,
     };
     let result1 = rc.create_user(&params);
     assert!(result1.is_ok());
@@ -95,3 +98,47 @@ fn test_list_user_limits() {
 
     rc.delete_user(params.name, false).unwrap();
 }
+
+#[test]
+fn test_set_user_limit_and_confirm_with_a_missing_value() {
+    let server = MockServer::start(vec![(204, "".to_owned()), (200, "[]".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let limit = EnforcedLimitParams::new(UserLimitTarget::MaxChannels, 20);
+    let result = rc.set_user_limit_and_confirm("limited-user", limit);
+    match result {
+        Err(Error::LimitNotApplied {
+            kind,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(kind, "max-channels");
+            assert_eq!(expected, 20);
+            assert_eq!(actual, None);
+        }
+        other => panic!("expected a LimitNotApplied error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_get_user_effective_limits_with_only_a_channel_limit() {
+    let server = MockServer::start(vec![(
+        200,
+        r#"[{"user": "limited-user", "value": {"max-channels": 20}}]"#.to_owned(),
+    )]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.get_user_effective_limits("limited-user");
+    assert!(
+        result.is_ok(),
+        "get_user_effective_limits returned {:?}",
+        result
+    );
+    let limits = result.unwrap();
+    assert_eq!(limits.max_channels, Some(20));
+    assert_eq!(limits.max_connections, None);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/user-limits/limited%2Duser "));
+}
@@ -14,7 +14,7 @@
 use rabbitmq_http_client::{blocking_api::Client, password_hashing, requests::UserParams};
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_users() {
@@ -46,6 +46,7 @@ fn test_list_users_without_permissions() {
         name: &username,
         password_hash: &password_hash,
         tags: "",
+        hashing_algorithm: None,
     };
     rc.create_user(&params).expect("failed to create a user");
 
@@ -83,6 +84,7 @@ fn test_user_creation() {
         name: "rust3",
         password_hash: &password_hash,
         tags: "management",
+        hashing_algorithm: None,
     };
     let result = rc.create_user(&params);
     assert!(result.is_ok());
@@ -102,6 +104,7 @@ fn test_user_deletion() {
         name,
         password_hash: &password_hash,
         tags: "management",
+        hashing_algorithm: None,
     };
     let result1 = rc.create_user(&params);
     assert!(result1.is_ok());
@@ -124,6 +127,7 @@ fn test_bulk_user_deletion() {
         name: name1,
         password_hash: &password_hash,
         tags: "management",
+        hashing_algorithm: None,
     };
     let result1 = rc.create_user(&params1);
     assert!(result1.is_ok());
@@ -133,6 +137,7 @@ fn test_bulk_user_deletion() {
         name: name2,
         password_hash: &password_hash,
         tags: "management",
+        hashing_algorithm: None,
     };
     let result2 = rc.create_user(&params2);
     assert!(result2.is_ok());
@@ -140,3 +145,60 @@ fn test_bulk_user_deletion() {
     let result2 = rc.delete_users(vec![name1, name2]);
     assert!(result2.is_ok());
 }
+
+#[test]
+fn test_current_user() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let result = rc.current_user();
+    assert!(result.is_ok(), "current_user returned {:?}", result);
+    let user = result.unwrap();
+    assert_eq!(user.name, USERNAME);
+    assert!(user.is_administrator());
+}
+
+#[test]
+fn test_current_user_is_administrator() {
+    let server = MockServer::start(vec![(
+        200,
+        r#"{"name": "ops", "tags": ["administrator"]}"#.to_owned(),
+    )]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    assert!(rc.current_user_is_administrator().unwrap());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/whoami "));
+}
+
+#[test]
+fn test_current_user_with_monitoring_tag() {
+    let server = MockServer::start(vec![
+        (
+            200,
+            r#"{"name": "observer", "tags": ["monitoring"]}"#.to_owned(),
+        ),
+        (
+            200,
+            r#"{"name": "observer", "tags": ["monitoring"]}"#.to_owned(),
+        ),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    assert!(!rc.current_user_is_administrator().unwrap());
+    assert!(rc.current_user_has_tag("monitoring").unwrap());
+}
+
+#[test]
+fn test_current_user_without_tags() {
+    let server = MockServer::start(vec![
+        (200, r#"{"name": "app", "tags": []}"#.to_owned()),
+        (200, r#"{"name": "app", "tags": []}"#.to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    assert!(!rc.current_user_is_administrator().unwrap());
+    assert!(!rc.current_user_has_tag("management").unwrap());
+}
@@ -14,11 +14,12 @@
 use rabbitmq_http_client::{
     blocking_api::Client,
     commons::VirtualHostLimitTarget,
+    error::Error,
     requests::{EnforcedLimitParams, VirtualHostParams},
 };
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_all_vhost_limits() {
@@ -78,3 +79,110 @@ fn test_list_vhost_limits() {
 
     rc.delete_vhost(vh_params.name, false).unwrap();
 }
+
+#[test]
+fn test_set_vhost_limit_and_confirm() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_set_vhost_limit_and_confirm");
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let limit = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxQueues, 250);
+    let result2 = rc.set_vhost_limit_and_confirm(vh_params.name, limit);
+    assert!(
+        result2.is_ok(),
+        "set_vhost_limit_and_confirm returned {:?}",
+        result2
+    );
+    assert_eq!(result2.unwrap(), 250);
+
+    rc.delete_vhost(vh_params.name, false).unwrap();
+}
+
+#[test]
+fn test_set_vhost_limit_and_confirm_with_a_mismatched_value() {
+    let server = MockServer::start(vec![
+        (204, "".to_owned()),
+        (
+            200,
+            r#"[{"vhost": "limited", "value": {"max-queues": 100}}]"#.to_owned(),
+        ),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let limit = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxQueues, 250);
+    let result = rc.set_vhost_limit_and_confirm("limited", limit);
+    match result {
+        Err(Error::LimitNotApplied {
+            kind,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(kind, "max-queues");
+            assert_eq!(expected, 250);
+            assert_eq!(actual, Some(100));
+        }
+        other => panic!("expected a LimitNotApplied error, got {:?}", other),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("PUT /api/vhost-limits/limited/max%2Dqueues"));
+    assert!(requests[1].starts_with("GET /api/vhost-limits/limited"));
+}
+
+#[test]
+fn test_get_vhost_effective_limits() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+
+    let vh_params = VirtualHostParams::named("test_get_vhost_effective_limits");
+    let _ = rc.delete_vhost(vh_params.name, true);
+    let result1 = rc.create_vhost(&vh_params);
+    assert!(result1.is_ok());
+
+    let result2 = rc.get_vhost_effective_limits(vh_params.name);
+    assert!(
+        result2.is_ok(),
+        "get_vhost_effective_limits returned {:?}",
+        result2
+    );
+    let limits = result2.unwrap();
+    assert_eq!(limits.max_connections, None);
+    assert_eq!(limits.max_queues, None);
+
+    let limit = EnforcedLimitParams::new(VirtualHostLimitTarget::MaxQueues, 300);
+    let result3 = rc.set_vhost_limit(vh_params.name, limit);
+    assert!(result3.is_ok());
+
+    let limits = rc.get_vhost_effective_limits(vh_params.name).unwrap();
+    assert_eq!(limits.max_connections, None);
+    assert_eq!(limits.max_queues, Some(300));
+
+    rc.delete_vhost(vh_params.name, false).unwrap();
+}
+
+#[test]
+fn test_get_vhost_effective_limits_with_both_limits_set() {
+    let server = MockServer::start(vec![(
+        200,
+        r#"[{"vhost": "limited", "value": {"max-queues": 100, "max-connections": 50}}]"#.to_owned(),
+    )]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let limits = rc.get_vhost_effective_limits("limited").unwrap();
+    assert_eq!(limits.max_connections, Some(50));
+    assert_eq!(limits.max_queues, Some(100));
+}
+
+#[test]
+fn test_get_vhost_effective_limits_without_limits() {
+    let server = MockServer::start(vec![(200, "[]".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let limits = rc.get_vhost_effective_limits("unlimited").unwrap();
+    assert_eq!(limits.max_connections, None);
+    assert_eq!(limits.max_queues, None);
+}
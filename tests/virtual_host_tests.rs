@@ -11,10 +11,15 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use rabbitmq_http_client::{blocking_api::Client, commons::QueueType, requests::VirtualHostParams};
+use rabbitmq_http_client::{
+    blocking_api::Client,
+    commons::QueueType,
+    requests::{QueueParams, VirtualHostParams},
+};
+use serde_json::json;
 
 mod test_helpers;
-use crate::test_helpers::{endpoint, PASSWORD, USERNAME};
+use crate::test_helpers::{endpoint, MockServer, PASSWORD, USERNAME};
 
 #[test]
 fn test_list_vhosts() {
@@ -131,3 +136,239 @@ fn test_delete_vhost() {
     let result3 = rc.get_vhost(name);
     assert!(result3.is_err());
 }
+
+#[test]
+fn test_delete_vhost_with_report() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let name = "rust_test_delete_vhost_with_report";
+
+    let _ = rc.delete_vhost(name, true);
+
+    let params = VirtualHostParams::named(name);
+    let result1 = rc.create_vhost(&params);
+    assert!(result1.is_ok());
+
+    let q1 = QueueParams::new_durable_classic_queue("rust.tests.cq.1", None);
+    let result2 = rc.declare_queue(name, &q1);
+    assert!(result2.is_ok(), "declare_queue returned {:?}", result2);
+    let q2 = QueueParams::new_quorum_queue("rust.tests.qq.1", None);
+    let result3 = rc.declare_queue(name, &q2);
+    assert!(result3.is_ok(), "declare_queue returned {:?}", result3);
+
+    let result4 = rc.delete_vhost_with_report(name, false);
+    assert!(
+        result4.is_ok(),
+        "delete_vhost_with_report returned {:?}",
+        result4
+    );
+    let report = result4.unwrap();
+    assert!(report.deleted);
+    assert_eq!(report.vhost, name);
+    assert_eq!(report.queue_count, 2);
+    // the default exchange and the amq.* ones are not counted
+    assert_eq!(report.exchange_count, 0);
+
+    let result5 = rc.get_vhost(name);
+    assert!(result5.is_err());
+
+    let result6 = rc.delete_vhost_with_report(name, true);
+    assert!(
+        result6.is_ok(),
+        "delete_vhost_with_report returned {:?}",
+        result6
+    );
+    assert!(!result6.unwrap().deleted);
+}
+
+#[test]
+fn test_virtual_host_params_serialization_with_default_queue_type_and_tags() {
+    let params = VirtualHostParams {
+        name: "vh.quorum",
+        description: None,
+        tags: Some(vec!["production", "tenant-a"]),
+        default_queue_type: Some(QueueType::Quorum),
+        tracing: false,
+    };
+
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "name": "vh.quorum",
+            "tags": ["production", "tenant-a"],
+            "default_queue_type": "quorum",
+            "tracing": false
+        })
+    );
+}
+
+#[test]
+fn test_virtual_host_params_serialization_omits_unset_metadata() {
+    let params = VirtualHostParams::named("vh.defaults");
+
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({"name": "vh.defaults", "tracing": false})
+    );
+}
+
+#[test]
+fn test_create_vhost_with_quorum_default_queue_type() {
+    let endpoint = endpoint();
+    let rc = Client::new(&endpoint, USERNAME, PASSWORD);
+    let name = "rust_test_create_vhost_with_quorum_default_queue_type";
+
+    let _ = rc.delete_vhost(name, true);
+
+    let params = VirtualHostParams {
+        name,
+        description: None,
+        tags: Some(vec!["tag-a", "tag-b"]),
+        default_queue_type: Some(QueueType::Quorum),
+        tracing: false,
+    };
+    let result1 = rc.create_vhost(&params);
+    assert!(result1.is_ok(), "create_vhost returned {:?}", result1);
+
+    let vh = rc.get_vhost(name).unwrap();
+    assert_eq!(vh.default_queue_type, Some("quorum".to_owned()));
+
+    let _ = rc.delete_vhost(name, false);
+}
+
+#[test]
+fn test_vhost_contents_summary() {
+    let queues = json!([
+        {"name": "cq.1", "vhost": "vh.1", "type": "classic", "durable": true,
+         "auto_delete": false, "exclusive": false, "arguments": {}, "messages": 10},
+        {"name": "qq.1", "vhost": "vh.1", "type": "quorum", "durable": true,
+         "auto_delete": false, "exclusive": false, "arguments": {}, "messages": 32}
+    ]);
+    let exchanges = json!([
+        {"name": "", "vhost": "vh.1", "type": "direct", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "amq.fanout", "vhost": "vh.1", "type": "fanout", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "events", "vhost": "vh.1", "type": "topic", "durable": true,
+         "auto_delete": false, "arguments": {}}
+    ]);
+    let bindings = json!([
+        {"source": "", "vhost": "vh.1", "destination": "cq.1", "destination_type": "queue",
+         "routing_key": "cq.1", "arguments": {}, "properties_key": "cq.1"},
+        {"source": "", "vhost": "vh.1", "destination": "qq.1", "destination_type": "queue",
+         "routing_key": "qq.1", "arguments": {}, "properties_key": "qq.1"},
+        {"source": "events", "vhost": "vh.1", "destination": "cq.1", "destination_type": "queue",
+         "routing_key": "events.#", "arguments": {}, "properties_key": "events.%23"}
+    ]);
+    let server = MockServer::start(vec![
+        (200, queues.to_string()),
+        (200, exchanges.to_string()),
+        (200, bindings.to_string()),
+        (200, "[]".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.vhost_contents_summary("vh.1");
+    assert!(
+        result.is_ok(),
+        "vhost_contents_summary returned {:?}",
+        result
+    );
+    let summary = result.unwrap();
+    assert_eq!(summary.vhost, "vh.1");
+    assert_eq!(summary.queue_count, 2);
+    assert_eq!(summary.exchange_count, 1);
+    assert_eq!(summary.binding_count, 1);
+    assert_eq!(summary.connection_count, 0);
+    assert_eq!(summary.message_count, 42);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].starts_with("GET /api/queues/vh%2E1 "));
+    assert!(requests[1].starts_with("GET /api/exchanges/vh%2E1 "));
+    assert!(requests[2].starts_with("GET /api/bindings/vh%2E1 "));
+    assert!(requests[3].starts_with("GET /api/vhosts/vh%2E1/connections "));
+}
+
+#[test]
+fn test_delete_vhost_with_report_does_not_count_built_in_exchanges() {
+    let vhost = json!({"name": "vh.1", "tracing": false, "metadata": {"tags": []}});
+    let queues = json!([
+        {"name": "cq.1", "vhost": "vh.1", "type": "classic", "durable": true,
+         "auto_delete": false, "exclusive": false, "arguments": {}, "messages": 10}
+    ]);
+    let exchanges = json!([
+        {"name": "", "vhost": "vh.1", "type": "direct", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "amq.fanout", "vhost": "vh.1", "type": "fanout", "durable": true,
+         "auto_delete": false, "arguments": {}},
+        {"name": "events", "vhost": "vh.1", "type": "topic", "durable": true,
+         "auto_delete": false, "arguments": {}}
+    ]);
+    let server = MockServer::start(vec![
+        (200, vhost.to_string()),
+        (200, queues.to_string()),
+        (200, "[]".to_owned()),
+        (200, exchanges.to_string()),
+        (204, "".to_owned()),
+    ]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let result = rc.delete_vhost_with_report("vh.1", false);
+    assert!(
+        result.is_ok(),
+        "delete_vhost_with_report returned {:?}",
+        result
+    );
+    let report = result.unwrap();
+    assert!(report.deleted);
+    assert_eq!(report.queue_count, 1);
+    // consistent with vhost_contents_summary
+    assert_eq!(report.exchange_count, 1);
+    assert_eq!(report.message_count, 10);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 5);
+    assert!(requests[3].starts_with("GET /api/exchanges/vh%2E1 "));
+    assert!(requests[4].starts_with("DELETE /api/vhosts/vh%2E1 "));
+}
+
+#[test]
+fn test_create_vhost_if_absent_when_it_is_absent() {
+    let server = MockServer::start(vec![(404, "".to_owned()), (201, "".to_owned())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = VirtualHostParams::named("vh.new");
+    let result = rc.create_vhost_if_absent(&params);
+    assert!(
+        result.is_ok(),
+        "create_vhost_if_absent returned {:?}",
+        result
+    );
+    assert!(result.unwrap());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /api/vhosts/vh%2Enew "));
+    assert!(requests[1].starts_with("PUT /api/vhosts/vh%2Enew "));
+}
+
+#[test]
+fn test_create_vhost_if_absent_when_it_already_exists() {
+    let vhost = json!({"name": "vh.existing", "tracing": false, "metadata": {"tags": []}});
+    let server = MockServer::start(vec![(200, vhost.to_string())]);
+    let rc = Client::new(&server.endpoint, USERNAME, PASSWORD);
+
+    let params = VirtualHostParams::named("vh.existing");
+    let result = rc.create_vhost_if_absent(&params);
+    assert!(
+        result.is_ok(),
+        "create_vhost_if_absent returned {:?}",
+        result
+    );
+    assert!(!result.unwrap());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /api/vhosts/vh%2Eexisting "));
+}